@@ -0,0 +1,73 @@
+// packs the bundled word lists at build time; see src/wordle/dictionary.rs
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const WORD_LENGTH: usize = 5;
+const ANSWERS_PATH: &str = "src/wordle/files/answer.txt";
+const GUESSES_PATH: &str = "src/wordle/files/guess.txt";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", ANSWERS_PATH);
+    println!("cargo:rerun-if-changed={}", GUESSES_PATH);
+
+    let answers = read_words(ANSWERS_PATH);
+    let guesses = read_words(GUESSES_PATH);
+
+    // every allowed word, sorted for binary search
+    let mut dictionary: Vec<&String> = answers.iter().chain(&guesses).collect();
+    dictionary.sort();
+    dictionary.dedup();
+
+    let code = format!(
+        "// generated by build.rs from the bundled word lists\n\
+         // answers in list order; daily puzzles depend on it\n\
+         pub(crate) static ANSWERS: [&str; {}] = [{}];\n\
+         pub(crate) static GUESSES: [&str; {}] = [{}];\n\
+         // answers and guesses; sorted and deduplicated\n\
+         pub(crate) static DICTIONARY: [[u8; {}]; {}] = [{}];\n",
+        answers.len(),
+        string_items(&answers),
+        guesses.len(),
+        string_items(&guesses),
+        WORD_LENGTH,
+        dictionary.len(),
+        dictionary
+            .iter()
+            .map(|word| format!("*b\"{}\"", word))
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("words.rs"), code).expect("word lists are written");
+}
+
+// one word per line; blank lines are skipped, anything else that is not a word fails the build
+fn read_words(path: &str) -> Vec<String> {
+    let data = fs::read_to_string(path).unwrap_or_else(|error| panic!("{}: {}", path, error));
+
+    data.lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|word| !word.is_empty())
+        .inspect(|word| {
+            let is_valid =
+                word.len() == WORD_LENGTH && word.chars().all(|x| x.is_ascii_lowercase());
+            assert!(
+                is_valid,
+                "{}: '{}' is not a {} letter word",
+                path, word, WORD_LENGTH
+            );
+        })
+        .collect()
+}
+
+fn string_items(words: &[String]) -> String {
+    words
+        .iter()
+        .map(|word| format!("\"{}\"", word))
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,236 @@
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::wordle;
+use crate::wordle::animation::{AnimationSettings, AnimationSpeed};
+use crate::wordle::data::WordListSource;
+use crate::wordle::error::WordlError;
+use crate::wordle::model::{GameMode, GameOptions};
+use crate::wordle::settings::Settings;
+use crate::wordle::solver::Strategy;
+use crate::wordle::utils::WORD_LENGTH;
+
+/// Terminal Wordle game. Web like with keyboard hints and slow reveal animations.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Play the daily wordle. Everyone gets the same word for the day, new word at local midnight
+    #[arg(long)]
+    pub daily: bool,
+
+    /// Absurdle. Wordle is not picked up front; every guess keeps as many words possible as it can
+    #[arg(long, conflicts_with_all = ["daily", "boards"])]
+    pub absurdle: bool,
+
+    /// Hard mode. Revealed hints must be used in subsequent guesses; on every unsolved board in
+    /// multi board modes
+    #[arg(long)]
+    pub hard: bool,
+
+    /// Number of wordles guessed at once; 2 for Dordle, 4 for Quordle and 8 for Octordle
+    #[arg(long, default_value_t = 1, value_parser = PossibleValuesParser::new(["1", "2", "4", "8"]).map(|x| x.parse::<usize>().unwrap()))]
+    pub boards: usize,
+
+    /// Beat the clock. Seconds allowed for a wordle; game is lost when the countdown hits zero
+    #[arg(long, value_name = "SECONDS", conflicts_with = "daily", value_parser = clap::value_parser!(u64).range(1..))]
+    pub time_limit: Option<u64>,
+
+    /// Speedrun. Play N consecutive wordles; total time and splits are recorded
+    #[arg(long, value_name = "N", conflicts_with_all = ["daily", "absurdle"], value_parser = clap::value_parser!(u64).range(1..=100).map(|x| x as usize))]
+    pub speedrun: Option<usize>,
+
+    /// Survival. Words are played one after the other from a shared attempt budget; unused attempts
+    /// roll over and the run ends once a word is not solved. Budget starts at --attempts
+    #[arg(long, conflicts_with_all = ["daily", "absurdle", "speedrun"])]
+    pub survival: bool,
+
+    /// Number of letters in the wordle (4 to 8). Defaults to 5. Word lists other than 5 letters are
+    /// loaded from <data directory>/wordl/words/<N>/answers.txt and guesses.txt
+    #[arg(long, global = true)]
+    pub length: Option<usize>,
+
+    /// Answer list file (one word per line). Replaces the default answers
+    #[arg(long, global = true, value_name = "FILE")]
+    pub answers: Option<PathBuf>,
+
+    /// Allowed guesses file (one word per line). Replaces the default guesses; must have every answer
+    #[arg(long, global = true, value_name = "FILE")]
+    pub words: Option<PathBuf>,
+
+    /// Named word list pack from <config directory>/wordl/packs/<NAME>/ with answers.txt and
+    /// optional guesses.txt
+    #[arg(long, global = true, value_name = "NAME", conflicts_with_all = ["answers", "words"])]
+    pub pack: Option<String>,
+
+    /// Number of guesses allowed (1 to 13). Defaults to 6, plus one for every extra board
+    #[arg(long, global = true, value_parser = clap::value_parser!(u64).range(1..=13).map(|x| x as usize))]
+    pub attempts: Option<usize>,
+
+    /// Speed of the tile animations. Defaults to normal
+    #[arg(long, value_enum, value_name = "SPEED")]
+    pub animation_speed: Option<AnimationSpeedArg>,
+
+    /// Reduced motion. Rows are revealed at once; nothing flips, pops, bounces or shakes
+    #[arg(long)]
+    pub reduced_motion: bool,
+
+    /// Config file. Defaults to <config directory>/wordl/config.toml; command line options take
+    /// precedence over the config file
+    #[arg(long, global = true, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    // theme, keyboard layout and key bindings; set only from the config file
+    #[arg(skip)]
+    pub settings: Settings,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve wordles with the built-in solver, without the terminal interface
+    Solve(SolveArgs),
+    /// Create challenge codes to share a chosen wordle
+    Challenge(ChallengeArgs),
+    /// Play the game; same as running without a command
+    Play(PlayArgs),
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ChallengeArgs {
+    #[command(subcommand)]
+    pub command: ChallengeCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ChallengeCommand {
+    /// Print the challenge code for the word; the word is not visible in the code
+    Create {
+        /// Word to be guessed (4 to 8 letters, from the word lists)
+        word: String,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+pub struct PlayArgs {
+    /// Play the wordle of a challenge code created with `wordl challenge create`
+    #[arg(long)]
+    pub code: Option<String>,
+}
+
+#[derive(Debug, clap::Args)]
+#[command(group(clap::ArgGroup::new("target").required(true).args(["all", "answer"])))]
+pub struct SolveArgs {
+    /// Strategy used to pick the next guess
+    #[arg(long, value_enum, default_value_t = StrategyArg::Entropy)]
+    pub strategy: StrategyArg,
+
+    /// Solve every wordle in the answer list and print a summary
+    #[arg(long)]
+    pub all: bool,
+
+    /// Solve the given wordle and print the guess by guess trace
+    #[arg(long)]
+    pub answer: Option<String>,
+
+    /// Precompute the feedback of every guess against every wordle; faster for `--all`, but
+    /// takes more memory
+    #[arg(long)]
+    pub pattern_matrix: bool,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum StrategyArg {
+    /// Guess with the highest expected information
+    Entropy,
+    /// First remaining candidate
+    FirstCandidate,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnimationSpeedArg {
+    /// No animations; guesses are revealed right away
+    Instant,
+    Fast,
+    #[default]
+    Normal,
+    Slow,
+}
+
+impl Args {
+    pub fn mode(&self) -> GameMode {
+        if self.daily {
+            GameMode::Daily
+        } else if self.absurdle {
+            GameMode::Absurdle
+        } else {
+            GameMode::Random
+        }
+    }
+
+    // `None` when the default word lists are used
+    pub fn word_list_source(&self) -> Result<Option<WordListSource>, WordlError> {
+        if let Some(name) = &self.pack {
+            return wordle::data::pack_source(name).map(Some);
+        }
+
+        Ok(
+            (self.answers.is_some() || self.words.is_some()).then(|| WordListSource {
+                answers: self.answers.clone(),
+                guesses: self.words.clone(),
+            }),
+        )
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.length.unwrap_or(WORD_LENGTH)
+    }
+
+    pub fn max_attempts(&self) -> usize {
+        self.attempts
+            .unwrap_or(wordle::game::default_max_attempts(self.boards))
+    }
+
+    pub fn options(&self) -> GameOptions {
+        GameOptions {
+            mode: self.mode(),
+            hard_mode: self.hard,
+            word_length: self.word_length(),
+            max_attempts: self.max_attempts(),
+            boards: self.boards,
+            time_limit: self.time_limit.map(Duration::from_secs),
+            speedrun: self.speedrun,
+            survival: self.survival,
+            animation: AnimationSettings {
+                speed: self.animation_speed.unwrap_or_default().into(),
+                reduced_motion: self.reduced_motion,
+            },
+            settings: self.settings,
+        }
+    }
+}
+
+impl From<StrategyArg> for Strategy {
+    fn from(strategy: StrategyArg) -> Self {
+        match strategy {
+            StrategyArg::Entropy => Strategy::Entropy,
+            StrategyArg::FirstCandidate => Strategy::FirstCandidate,
+        }
+    }
+}
+
+impl From<AnimationSpeedArg> for AnimationSpeed {
+    fn from(speed: AnimationSpeedArg) -> Self {
+        match speed {
+            AnimationSpeedArg::Instant => AnimationSpeed::Instant,
+            AnimationSpeedArg::Fast => AnimationSpeed::Fast,
+            AnimationSpeedArg::Normal => AnimationSpeed::Normal,
+            AnimationSpeedArg::Slow => AnimationSpeed::Slow,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::cli::{Args, ChallengeArgs, ChallengeCommand};
+use crate::wordle;
+
+// `wordl challenge`; prints challenge codes
+pub fn run(challenge_args: ChallengeArgs, args: &Args) -> Result<()> {
+    match challenge_args.command {
+        ChallengeCommand::Create { word } => create(&word.to_lowercase(), args),
+    }
+}
+
+fn create(word: &str, args: &Args) -> Result<()> {
+    let code = wordle::challenge::encode(word)?;
+
+    // typos would make the challenge unsolvable; only answers are accepted, as hints and analysis
+    // look for the wordle among them
+    let word_lists = super::word_lists(args, word.len())?;
+    if !word_lists.valid_wordles.iter().any(|x| x == word) {
+        return Err(eyre!("{} is not in the answer list", word));
+    }
+
+    println!("Challenge code: {}", code);
+    println!("Play it with: wordl play --code {}", code);
+
+    Ok(())
+}
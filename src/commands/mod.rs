@@ -0,0 +1,23 @@
+use color_eyre::Result;
+
+use crate::cli::Args;
+use crate::wordle;
+use crate::wordle::data::WordLists;
+
+pub mod challenge;
+pub mod solve;
+
+// word lists picked with the command line options; problems that do not stop the lists from being
+// used are printed as warnings
+pub fn word_lists(args: &Args, length: usize) -> Result<WordLists> {
+    let Some(source) = args.word_list_source()? else {
+        return Ok(wordle::data::word_lists(length)?);
+    };
+
+    let (word_lists, warnings) = wordle::data::load_word_lists(&source, length)?;
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    Ok(word_lists)
+}
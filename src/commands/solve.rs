@@ -0,0 +1,147 @@
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::cli::SolveArgs;
+use crate::share;
+use crate::wordle;
+use crate::wordle::data::WordLists;
+use crate::wordle::model::LetterStatus;
+use crate::wordle::solver::{self, Solver};
+
+// solver keeps guessing after the allowed attempts, so that worst cases can be measured
+const MAX_SOLVER_GUESSES: usize = 20;
+// number of worst case wordles listed in the summary
+const WORST_CASES: usize = 10;
+
+// `wordl solve`; runs the solver headless
+pub fn run(
+    args: SolveArgs,
+    word_lists: WordLists,
+    length: usize,
+    max_attempts: usize,
+) -> Result<()> {
+    let WordLists {
+        valid_wordles,
+        valid_guesses,
+        ..
+    } = word_lists;
+    let valid_wordles = valid_wordles.to_vec();
+    let mut solver = Solver::new(
+        args.strategy.into(),
+        valid_wordles.clone(),
+        valid_guesses.to_vec(),
+    );
+    if args.pattern_matrix {
+        solver = solver.with_pattern_matrix();
+    }
+
+    match args.answer {
+        Some(answer) => trace(
+            &mut solver,
+            &answer.to_lowercase(),
+            length,
+            max_attempts,
+            &valid_wordles,
+        ),
+        None => benchmark(&mut solver, max_attempts, &valid_wordles),
+    }
+}
+
+// prints guess by guess trace for a single wordle
+fn trace(
+    solver: &mut Solver,
+    answer: &str,
+    length: usize,
+    max_attempts: usize,
+    valid_wordles: &[String],
+) -> Result<()> {
+    wordle::utils::validate_word(answer, length)?;
+    if !valid_wordles.iter().any(|x| x == answer) {
+        return Err(eyre!("{} is not in the answer list", answer));
+    }
+
+    let guesses = solver.solve(answer, MAX_SOLVER_GUESSES)?;
+
+    for (index, guess) in guesses.iter().enumerate() {
+        let word: String = guess.iter().map(|x| x.letter).collect();
+        let remaining = solver::candidates(&guesses[..=index], valid_wordles).len();
+
+        println!(
+            "{}. {} {} ({} left)",
+            index + 1,
+            word.to_uppercase(),
+            share::emoji_row(guess),
+            remaining
+        );
+    }
+
+    let attempts = guesses.len();
+    if !is_solved(&guesses) {
+        println!("Failed; not solved in {} guesses", attempts);
+    } else if attempts <= max_attempts {
+        println!("Solved in {}/{}", attempts, max_attempts);
+    } else {
+        println!("Failed; solved in {} guesses", attempts);
+    }
+
+    Ok(())
+}
+
+// solves every wordle in the answer list and prints the summary
+// wordles the solver gave up on are listed apart; they are not part of the average or the worst
+// cases
+fn benchmark(solver: &mut Solver, max_attempts: usize, valid_wordles: &[String]) -> Result<()> {
+    let mut results: Vec<(String, usize)> = Vec::with_capacity(valid_wordles.len());
+    let mut unsolved: Vec<String> = Vec::new();
+
+    for answer in valid_wordles {
+        let guesses = solver.solve(answer, MAX_SOLVER_GUESSES)?;
+        if is_solved(&guesses) {
+            results.push((answer.to_string(), guesses.len()));
+        } else {
+            unsolved.push(answer.to_string());
+        }
+    }
+
+    let total_guesses: usize = results.iter().map(|(_, attempts)| attempts).sum();
+    let failures = results
+        .iter()
+        .filter(|(_, attempts)| *attempts > max_attempts)
+        .count();
+
+    println!("Wordles: {}", results.len() + unsolved.len());
+    println!(
+        "Average guesses: {:.3}",
+        total_guesses as f64 / results.len().max(1) as f64
+    );
+    println!(
+        "Failures (more than {} guesses): {}",
+        max_attempts,
+        failures + unsolved.len()
+    );
+    if !unsolved.is_empty() {
+        println!(
+            "Not solved in {} guesses: {}",
+            MAX_SOLVER_GUESSES,
+            unsolved.len()
+        );
+        for answer in unsolved.iter().take(WORST_CASES) {
+            println!("  {}", answer.to_uppercase());
+        }
+    }
+
+    results.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    println!("Worst cases:");
+    for (answer, attempts) in results.iter().take(WORST_CASES) {
+        println!("  {} {}", answer.to_uppercase(), attempts);
+    }
+
+    Ok(())
+}
+
+// solver stops at the correct guess, or gives up
+fn is_solved(guesses: &[Vec<LetterStatus>]) -> bool {
+    guesses
+        .last()
+        .is_some_and(|guess| wordle::utils::is_correct_guess(guess.clone()))
+}
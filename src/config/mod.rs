@@ -0,0 +1,242 @@
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{AnimationSpeedArg, Args, Command};
+use crate::wordle;
+use crate::wordle::error::WordlError;
+use crate::wordle::settings::{KeyBindings, KeyboardLayout, Settings, Theme};
+use wordl::storage;
+
+// $XDG_CONFIG_HOME/wordl/config.toml
+const CONFIG_FILE: &str = "config.toml";
+
+// defaults read from the config file; every setting is optional
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    pub mode: Option<DefaultMode>,
+    pub length: Option<usize>,
+    pub attempts: Option<usize>,
+    pub animation_speed: Option<AnimationSpeedArg>,
+    pub reduced_motion: bool,
+    pub theme: Theme,
+    pub keyboard: KeyboardLayout,
+    pub keys: KeyBindings,
+}
+
+// game mode started without any mode options
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DefaultMode {
+    Random,
+    Daily,
+    Absurdle,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    // file given with `--config` cannot be read
+    Read {
+        path: PathBuf,
+        error: io::Error,
+    },
+    // not valid toml, or has unknown settings or values
+    Parse {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    // values are not supported (eg: word length) or key bindings clash
+    Invalid {
+        path: PathBuf,
+        error: WordlError,
+    },
+}
+
+impl Config {
+    // config from the given file, or from the config directory; missing default file is not an
+    // error
+    pub fn load(path: Option<&Path>) -> Result<Config, ConfigError> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match storage::config_file(CONFIG_FILE) {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Config::default()),
+            },
+        };
+
+        let text = fs::read_to_string(&path).map_err(|error| ConfigError::Read {
+            path: path.clone(),
+            error,
+        })?;
+        let config = Config::parse(&text).map_err(|error| ConfigError::Parse {
+            path: path.clone(),
+            error,
+        })?;
+        config
+            .validate()
+            .map_err(|error| ConfigError::Invalid { path, error })?;
+
+        Ok(config)
+    }
+
+    fn parse(text: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    fn validate(&self) -> Result<(), WordlError> {
+        if let Some(length) = self.length {
+            wordle::utils::validate_word_length(length)?;
+        }
+        if let Some(attempts) = self.attempts {
+            if !(wordle::game::MIN_ATTEMPTS..=wordle::game::MAX_ATTEMPTS).contains(&attempts) {
+                return Err(WordlError::UnsupportedAttempts(attempts));
+            }
+        }
+
+        self.keys.validate()
+    }
+
+    // command line options take precedence over the config file
+    pub fn apply(&self, args: &mut Args) {
+        // default mode is used only when no other mode is chosen (including challenge codes)
+        let has_code = matches!(&args.command, Some(Command::Play(play)) if play.code.is_some());
+        let has_mode = args.daily
+            || args.absurdle
+            || args.boards > 1
+            || args.time_limit.is_some()
+            || args.speedrun.is_some()
+            || args.survival
+            || has_code;
+        if !has_mode {
+            match self.mode {
+                Some(DefaultMode::Daily) => args.daily = true,
+                Some(DefaultMode::Absurdle) => args.absurdle = true,
+                Some(DefaultMode::Random) | None => {}
+            }
+        }
+
+        args.length = args.length.or(self.length);
+        args.attempts = args.attempts.or(self.attempts);
+        args.animation_speed = args.animation_speed.or(self.animation_speed);
+        args.reduced_motion |= self.reduced_motion;
+        args.settings = Settings {
+            theme: self.theme,
+            keyboard_layout: self.keyboard,
+            key_bindings: self.keys,
+        };
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, error } => {
+                write!(f, "Cannot read config file {}: {}", path.display(), error)
+            }
+            ConfigError::Parse { path, error } => {
+                write!(f, "Invalid config file {}\n{}", path.display(), error)
+            }
+            ConfigError::Invalid { path, error } => {
+                write!(f, "Invalid config file {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+    use crate::wordle::settings::{KeyBinding, KeyName};
+    use clap::Parser;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse(
+            r#"
+            mode = "daily"
+            length = 6
+            animation-speed = "fast"
+            theme = "high-contrast"
+            keyboard = "azerty"
+
+            [keys]
+            hints = "f1"
+            quit = "ctrl-q"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.mode, Some(DefaultMode::Daily));
+        assert_eq!(config.length, Some(6));
+        assert_eq!(config.attempts, None);
+        assert_eq!(config.animation_speed, Some(AnimationSpeedArg::Fast));
+        assert_eq!(config.theme, Theme::HighContrast);
+        assert_eq!(config.keyboard, KeyboardLayout::Azerty);
+        assert_eq!(
+            config.keys.hints,
+            KeyBinding {
+                key: KeyName::F(1),
+                ctrl: false
+            }
+        );
+        // bindings that are not given keep the defaults
+        assert_eq!(config.keys.stats, KeyBindings::default().stats);
+        assert_eq!(config.validate(), Ok(()));
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_config_errors() {
+        // unknown settings, values and keys are reported with their position
+        for text in [
+            "colour = \"dark\"",
+            "theme = \"neon\"",
+            "length = \"five\"",
+            "[keys]\nquit = \"q\"",
+        ] {
+            let error = Config::parse(text).unwrap_err().to_string();
+            assert!(error.contains("line"), "{}", error);
+        }
+
+        let config = Config::parse("length = 9").unwrap();
+        assert_eq!(config.validate(), Err(WordlError::UnsupportedLength(9)));
+
+        let config = Config::parse("attempts = 0").unwrap();
+        assert_eq!(config.validate(), Err(WordlError::UnsupportedAttempts(0)));
+
+        let config = Config::parse("[keys]\nshare = \"ctrl-n\"").unwrap();
+        assert_eq!(
+            config.validate(),
+            Err(WordlError::DuplicateKeyBinding("Ctrl-N".into()))
+        );
+    }
+
+    #[test]
+    fn test_apply_config() {
+        let config = Config::parse("mode = \"absurdle\"\nlength = 6\nattempts = 8").unwrap();
+
+        let mut args = Args::parse_from(["wordl", "--attempts", "4"]);
+        config.apply(&mut args);
+        assert!(args.absurdle);
+        assert_eq!(args.word_length(), 6);
+        assert_eq!(args.max_attempts(), 4);
+
+        // mode options on the command line replace the default mode
+        let mut args = Args::parse_from(["wordl", "--boards", "4"]);
+        config.apply(&mut args);
+        assert!(!args.absurdle);
+
+        // timed games are not saved; daily puzzle would be replayed
+        let config = Config::parse("mode = \"daily\"").unwrap();
+        let mut args = Args::parse_from(["wordl", "--time-limit", "60"]);
+        config.apply(&mut args);
+        assert!(!args.daily);
+        assert!(Args::try_parse_from(["wordl", "--daily", "--time-limit", "60"]).is_err());
+    }
+}
@@ -92,6 +92,18 @@ impl EventHandler {
             .ok_or(color_eyre::eyre::eyre!("Unable to get event"))
     }
 
+    // runs blocking work (eg: solver) on a separate thread and sends its result as state update
+    pub fn send_blocking_message<F>(&self, work: F)
+    where
+        F: FnOnce() -> Message + Send + 'static,
+    {
+        let sender = self.sender.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let _ = sender.send(Event::StateUpdate(work()));
+        });
+    }
+
     // send delayed state update message
     pub async fn send_delayed_message(&self, delay: u64, message: Message) {
         let sender = self.sender.clone();
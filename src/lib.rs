@@ -0,0 +1,44 @@
+//! Wordle game engine used by the `wordl` terminal game.
+//!
+//! The engine does not depend on any terminal libraries. Terminal user interface is built only
+//! with the `tui` feature (enabled by default).
+//!
+//! ```
+//! use wordl::{Game, LetterState};
+//!
+//! let mut game = Game::new("pious");
+//! let feedback = game.guess("pilot").unwrap();
+//!
+//! assert_eq!(feedback[0].status, LetterState::Correct);
+//! ```
+
+pub mod wordle;
+
+// persistence and sharing are used by the `wordl` binary only; they are not part of the engine API
+#[cfg(feature = "tui")]
+#[doc(hidden)]
+pub mod save;
+#[cfg(feature = "tui")]
+#[doc(hidden)]
+pub mod share;
+#[cfg(feature = "tui")]
+#[doc(hidden)]
+pub mod stats;
+#[cfg(feature = "tui")]
+#[doc(hidden)]
+pub mod storage;
+
+// engine keeps stats in the model and loads word lists from the data directory; without the
+// terminal user interface, nothing records the stats
+#[cfg(not(feature = "tui"))]
+#[allow(dead_code)]
+mod stats;
+#[cfg(not(feature = "tui"))]
+mod storage;
+
+pub use wordle::{analysis, solver};
+
+pub use wordle::data::{valid_guesses, valid_wordles};
+pub use wordle::error::{HardModeViolation, WordlError};
+pub use wordle::game::{Game, GuessError};
+pub use wordle::model::{GameResult, KeyboardHints, LetterState, LetterStatus};
@@ -1,36 +1,99 @@
 // ref: https://ratatui.rs/concepts/application-patterns/the-elm-architecture/
 
+use clap::Parser;
 use ratatui::{backend::CrosstermBackend, Terminal};
 
+use cli::{Args, Command};
+use config::Config;
 use events::{Event, EventHandler};
+use save::SavedGame;
 use tui::Tui;
 use update::{handle_key_event, update};
-use wordle::model::{Model, RunningState};
+use wordle::model::{Message, Model, RunningState};
 
+// game engine and persistence come from the library crate
+use wordl::{save, share, stats, wordle};
+
+// animation frame rate; game clock is updated at the same rate
+const TICK_MS: u64 = 30;
+// without animations, frames are needed only for the game clock; easier on slow connections
+const CLOCK_TICK_MS: u64 = 250;
+
+pub mod cli;
+pub mod commands;
+pub mod config;
 pub mod events;
 pub mod tui;
 pub mod ui;
 pub mod update;
-pub mod wordle;
 
-#[tokio::main]
-async fn main() -> color_eyre::Result<()> {
+fn main() -> color_eyre::Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    let result = runtime.block_on(start());
+    // solver hints and analysis may still be running on blocking threads; quitting does not wait
+    // for them
+    runtime.shutdown_background();
+
+    result
+}
+
+async fn start() -> color_eyre::Result<()> {
     color_eyre::install()?;
-    let result = run().await;
-    result?;
+    let mut args = Args::parse();
+    // config problems are reported before the terminal is set up
+    Config::load(args.config.as_deref())?.apply(&mut args);
+
+    // headless commands do not need the terminal interface
+    match args.command.take() {
+        Some(Command::Solve(solve_args)) => {
+            let word_lists = commands::word_lists(&args, args.word_length())?;
+            commands::solve::run(
+                solve_args,
+                word_lists,
+                args.word_length(),
+                args.max_attempts(),
+            )?
+        }
+        Some(Command::Challenge(challenge_args)) => {
+            commands::challenge::run(challenge_args, &args)?
+        }
+        Some(Command::Play(play_args)) => run(args, play_args.code).await?,
+        None => run(args, None).await?,
+    }
 
     Ok(())
 }
 
-async fn run() -> color_eyre::Result<()> {
+async fn run(args: Args, code: Option<String>) -> color_eyre::Result<()> {
+    // challenge code decides the wordle, and the word length with it
+    let challenge = code
+        .map(|code| wordle::challenge::decode(&code))
+        .transpose()?;
+    let mut options = args.options();
+    if let Some(wordle) = &challenge {
+        options.word_length = wordle.len();
+    }
+
     // TEA - The ELM architecture
     // Model | Update | View
-    let mut model = Model::new();
+    let word_lists = commands::word_lists(&args, options.word_length)?;
+    let mut model = Model::with_word_lists(options, word_lists)?;
+    if let Some(wordle) = &challenge {
+        model.start_challenge(wordle)?;
+    } else if let Some(saved_game) = SavedGame::load(&model) {
+        // pick up unfinished game from the last session
+        saved_game.restore(&mut model);
+    }
 
     // init terminal
     let backend = CrosstermBackend::new(std::io::stderr());
     let terminal = Terminal::new(backend)?;
-    let events = EventHandler::new(250);
+    let tick_rate = if options.animation.has_motion() {
+        TICK_MS
+    } else {
+        CLOCK_TICK_MS
+    };
+    let events = EventHandler::new(tick_rate);
 
     let mut tui = Tui::new(terminal, events);
     tui.enter()?;
@@ -41,21 +104,34 @@ async fn run() -> color_eyre::Result<()> {
         tui.draw(&mut model)?;
         // Handle events (we will sending tick events periodically)
         match tui.events.next().await? {
-            Event::Tick => {}
+            Event::Tick => {
+                update(&mut model, Message::Tick, &tui.events).await;
+            }
             Event::Key(key_event) => {
-                if let Some(message) = handle_key_event(key_event) {
+                if let Some(message) = handle_key_event(&model, key_event) {
                     update(&mut model, message, &tui.events).await;
                 }
             }
             Event::StateUpdate(message) => {
                 update(&mut model, message, &tui.events).await;
             }
-            Event::Mouse(_) => {}
+            Event::Mouse(mouse_event) => {
+                if let Some(message) = ui::handle_mouse_event(&model, tui.size()?, mouse_event) {
+                    update(&mut model, message, &tui.events).await;
+                }
+            }
             Event::Resize(_, _) => {}
         }
     }
 
     // exit the terminal
     tui.exit()?;
+
+    // shared results that could not be copied; fallback for terminals without clipboard (OSC 52)
+    // support
+    if let Some(share_text) = model.share_text {
+        println!("{}", share_text);
+    }
+
     Ok(())
 }
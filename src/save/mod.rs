@@ -0,0 +1,449 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::storage;
+use crate::wordle;
+use crate::wordle::model::{Board, GameMode, Model, RunningState};
+
+// Unfinished game saved on quit and resumed on next launch
+// Daily puzzles are kept apart from the other games, finished or not, so that they cannot be
+// replayed on the same day
+// Only the guessed words are stored; letter statuses and keyboard hints are rebuilt on restore
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedGame {
+    // one wordle per board
+    pub wordles: Vec<String>,
+    pub mode: GameMode,
+    pub puzzle_number: Option<usize>,
+    pub hard_mode: bool,
+    // saves from older versions are 6 attempt games
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: usize,
+    pub guesses: Vec<String>,
+    pub active_guess: String,
+    // checksum of the word lists the game was played with; saves from older versions do not
+    // have it, their wordles are only looked up in the answer list
+    #[serde(default)]
+    pub word_lists: Option<u64>,
+}
+
+impl SavedGame {
+    pub fn from_model(model: &Model) -> Self {
+        SavedGame {
+            wordles: model.wordles(),
+            mode: model.mode,
+            puzzle_number: model.puzzle_number,
+            hard_mode: model.hard_mode,
+            max_attempts: model.max_attempts,
+            guesses: model
+                .boards
+                .iter()
+                .max_by_key(|board| board.guesses.len())
+                .map(|board| {
+                    board
+                        .guesses
+                        .iter()
+                        .map(|guess| guess.iter().map(|x| x.letter).collect())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            active_guess: model.active_guess.to_string(),
+            word_lists: Some(word_lists_checksum(model)),
+        }
+    }
+
+    // loads saved game (if any) from the data directory that can be resumed with the model's
+    // options
+    pub fn load(model: &Model) -> Option<Self> {
+        let saved_games = match model.mode {
+            GameMode::Daily => load_daily_games(),
+            _ => read_json(&save_file()?).into_iter().collect(),
+        };
+
+        saved_games
+            .into_iter()
+            .find(|saved_game| saved_game.can_restore(model))
+    }
+
+    // daily puzzles are saved next to the other daily puzzles of the day played with other
+    // options; every other game replaces the saved game
+    pub fn save(&self) -> io::Result<()> {
+        if self.mode != GameMode::Daily {
+            return match save_file() {
+                Some(path) => write_json(&path, self),
+                None => Ok(()),
+            };
+        }
+
+        let mut saved_games = load_daily_games();
+        saved_games.retain(|x| x.puzzle_number == self.puzzle_number && !x.has_same_options(self));
+        saved_games.push(self.clone());
+
+        match daily_file() {
+            Some(path) => write_json(&path, &saved_games),
+            None => Ok(()),
+        }
+    }
+
+    // removes saved game once it is over; games played with other options are kept
+    pub fn clear(&self) -> io::Result<()> {
+        let Some(path) = save_file() else {
+            return Ok(());
+        };
+
+        match read_json::<SavedGame>(&path) {
+            Some(saved_game) if saved_game.has_same_options(self) => fs::remove_file(path),
+            _ => Ok(()),
+        }
+    }
+
+    // saved game is resumed only if it was played with the same settings and word lists. Daily
+    // puzzles are resumed only on the same day
+    pub fn can_restore(&self, model: &Model) -> bool {
+        model.is_resumable()
+            && self.has_same_options(&SavedGame::from_model(model))
+            && self
+                .wordles
+                .iter()
+                .all(|wordle| model.valid_wordles.iter().any(|x| x == wordle))
+    }
+
+    // games of the same mode, settings and word lists; only then one can replace the other
+    fn has_same_options(&self, other: &SavedGame) -> bool {
+        // older saves are checked only against the answer list
+        let is_same_word_lists = match (self.word_lists, other.word_lists) {
+            (Some(checksum), Some(other_checksum)) => checksum == other_checksum,
+            _ => true,
+        };
+
+        self.mode == other.mode
+            && self.hard_mode == other.hard_mode
+            && self.max_attempts == other.max_attempts
+            && self.puzzle_number == other.puzzle_number
+            && self.wordles.len() == other.wordles.len()
+            && self
+                .wordles
+                .iter()
+                .all(|wordle| Some(wordle.chars().count()) == other.word_length())
+            && is_same_word_lists
+    }
+
+    fn word_length(&self) -> Option<usize> {
+        self.wordles.first().map(|wordle| wordle.chars().count())
+    }
+
+    // restores saved game into the model; keyboard hints are rebuilt from the guesses
+    pub fn restore(&self, model: &mut Model) {
+        model.boards = self
+            .wordles
+            .iter()
+            .map(|wordle| Board::new(wordle))
+            .collect();
+        model.puzzle_number = self.puzzle_number;
+        model.active_guess = self.active_guess.to_string();
+        // absurdle candidates are narrowed down again by replaying the guesses
+        if model.mode == GameMode::Absurdle {
+            model.candidates = model.valid_wordles.to_vec();
+        }
+
+        for guess in &self.guesses {
+            // game might have been over already (eg: edited save file)
+            if model.result().is_some() {
+                break;
+            }
+            // skip guesses that cannot be checked (eg: edited save file)
+            if model.narrow_candidates(guess).is_err() {
+                continue;
+            }
+            if let Ok(board_guesses) = model.check_guess(guess) {
+                model.apply_guess(&board_guesses);
+            }
+        }
+
+        model.running_state = match model.result() {
+            Some(result) => RunningState::Over(result),
+            None => RunningState::Waiting,
+        };
+    }
+}
+
+fn default_max_attempts() -> usize {
+    wordle::game::DEFAULT_ATTEMPTS
+}
+
+// $XDG_DATA_HOME/wordl/game.json
+fn save_file() -> Option<PathBuf> {
+    storage::data_file("game.json")
+}
+
+// $XDG_DATA_HOME/wordl/daily.json; daily puzzles of the day, one for every set of options
+fn daily_file() -> Option<PathBuf> {
+    storage::data_file("daily.json")
+}
+
+fn load_daily_games() -> Vec<SavedGame> {
+    daily_file()
+        .and_then(|path| read_json(&path))
+        .unwrap_or_default()
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let data = fs::read_to_string(path).ok()?;
+
+    serde_json::from_str(&data).ok()
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    storage::create_parent_dir(path)?;
+    fs::write(path, serde_json::to_string(value)?)
+}
+
+// FNV-1a of the answers and the allowed guesses, in order; words are separated by a newline and
+// the lists by a blank line
+fn word_lists_checksum(model: &Model) -> u64 {
+    let answers = model.valid_wordles.iter();
+    let guesses = model.valid_guesses.iter();
+    let words = answers.chain(std::iter::once("")).chain(guesses);
+
+    wordle::utils::fnv1a(words.flat_map(|word| word.bytes().chain(std::iter::once(b'\n'))))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::save::*;
+    use crate::wordle::model::{GameResult, LetterState};
+
+    fn saved_game(wordles: &[&str], guesses: &[&str]) -> SavedGame {
+        SavedGame {
+            wordles: wordles.iter().map(|x| x.to_string()).collect(),
+            mode: GameMode::Random,
+            puzzle_number: None,
+            hard_mode: false,
+            max_attempts: 6,
+            guesses: guesses.iter().map(|x| x.to_string()).collect(),
+            active_guess: "".into(),
+            word_lists: None,
+        }
+    }
+
+    #[test]
+    fn test_save_restore() {
+        let mut model = Model {
+            max_attempts: 6,
+            ..Model::default()
+        };
+        SavedGame {
+            active_guess: "pi".into(),
+            ..saved_game(&["pious"], &["piano", "smile"])
+        }
+        .restore(&mut model);
+
+        let saved_game = SavedGame::from_model(&model);
+        assert_eq!(saved_game.wordles, vec!["pious"]);
+        assert_eq!(saved_game.guesses, vec!["piano", "smile"]);
+        assert_eq!(saved_game.active_guess, "pi");
+
+        // keyboard hints are rebuilt
+        let board = &model.boards[0];
+        assert_eq!(board.guesses.len(), 2);
+        assert_eq!(
+            *board.keyboard_hints.get(&'p').unwrap(),
+            LetterState::Correct
+        );
+        assert_eq!(
+            *board.keyboard_hints.get(&'o').unwrap(),
+            LetterState::Incorrect
+        );
+        assert_eq!(
+            *board.keyboard_hints.get(&'a').unwrap(),
+            LetterState::NotPresent
+        );
+        assert_eq!(model.running_state, RunningState::Waiting);
+    }
+
+    #[test]
+    fn test_save_restore_boards() {
+        let mut model = Model {
+            max_attempts: 7,
+            ..Model::default()
+        };
+        saved_game(&["pious", "crane"], &["piano", "pious", "smile"]).restore(&mut model);
+
+        // solved board does not take any more guesses
+        assert_eq!(model.boards[0].guesses.len(), 2);
+        assert_eq!(model.boards[1].guesses.len(), 3);
+        assert_eq!(model.running_state, RunningState::Waiting);
+
+        let saved_game = SavedGame::from_model(&model);
+        assert_eq!(saved_game.wordles, vec!["pious", "crane"]);
+        assert_eq!(saved_game.guesses, vec!["piano", "pious", "smile"]);
+    }
+
+    #[test]
+    fn test_save_restore_absurdle() {
+        let mut model = Model {
+            mode: GameMode::Absurdle,
+            max_attempts: 6,
+            valid_wordles: vec!["pious".to_string(), "crank".into(), "crane".into()].into(),
+            ..Model::default()
+        };
+        SavedGame {
+            mode: GameMode::Absurdle,
+            ..saved_game(&["crank"], &["pilot", "crank"])
+        }
+        .restore(&mut model);
+
+        // candidates are narrowed down again
+        assert_eq!(model.candidates, vec!["crane"]);
+        assert_eq!(model.wordles(), vec!["crane"]);
+        assert_eq!(model.boards[0].guesses.len(), 2);
+        assert_eq!(model.running_state, RunningState::Waiting);
+    }
+
+    #[test]
+    fn test_can_restore() {
+        let model = Model {
+            boards: vec![Board::new("crane")],
+            mode: GameMode::Daily,
+            puzzle_number: Some(100),
+            max_attempts: 6,
+            valid_wordles: vec!["crane".to_string(), "pious".into()].into(),
+            ..Model::default()
+        };
+        let saved_game = SavedGame {
+            mode: GameMode::Daily,
+            puzzle_number: Some(100),
+            ..saved_game(&["pious"], &[])
+        };
+        assert!(saved_game.can_restore(&model));
+
+        // same word lists
+        let checksum = SavedGame::from_model(&model).word_lists;
+        assert!(checksum.is_some());
+        let checked_game = SavedGame {
+            word_lists: checksum,
+            ..saved_game.clone()
+        };
+        assert!(checked_game.can_restore(&model));
+
+        // other word lists (eg: a pack)
+        let pack_game = SavedGame {
+            word_lists: checksum.map(|x| x + 1),
+            ..saved_game.clone()
+        };
+        assert!(!pack_game.can_restore(&model));
+
+        // wordle that is not in the answer list
+        let unknown_game = SavedGame {
+            wordles: vec!["plumb".into()],
+            ..saved_game.clone()
+        };
+        assert!(!unknown_game.can_restore(&model));
+
+        // yesterday's daily puzzle
+        let stale_game = SavedGame {
+            puzzle_number: Some(99),
+            ..saved_game.clone()
+        };
+        assert!(!stale_game.can_restore(&model));
+
+        // different mode
+        let random_game = SavedGame {
+            mode: GameMode::Random,
+            puzzle_number: None,
+            ..saved_game.clone()
+        };
+        assert!(!random_game.can_restore(&model));
+
+        // different word length
+        let model = Model {
+            boards: vec![Board::new("wolf")],
+            ..model
+        };
+        assert!(!saved_game.can_restore(&model));
+
+        // different number of attempts
+        let model = Model {
+            boards: vec![Board::new("crane")],
+            max_attempts: 3,
+            ..model
+        };
+        assert!(!saved_game.can_restore(&model));
+
+        // different number of boards
+        let model = Model {
+            boards: vec![Board::new("crane"), Board::new("pious")],
+            max_attempts: 6,
+            ..model
+        };
+        assert!(!saved_game.can_restore(&model));
+    }
+
+    #[test]
+    fn test_has_same_options() {
+        let game = saved_game(&["pious"], &["piano"]);
+
+        // guesses do not matter; a game replaces the saved game of the same options
+        assert!(game.has_same_options(&saved_game(&["crane"], &[])));
+        assert!(!game.has_same_options(&SavedGame {
+            hard_mode: true,
+            ..game.clone()
+        }));
+        assert!(!game.has_same_options(&saved_game(&["wolf"], &[])));
+        assert!(!game.has_same_options(&saved_game(&["pious", "crane"], &[])));
+    }
+
+    #[test]
+    fn test_word_lists_checksum() {
+        let lists_model = |answers: &[&str], guesses: &[&str]| Model {
+            valid_wordles: answers
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .into(),
+            valid_guesses: guesses
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .into(),
+            ..Model::default()
+        };
+        let checksum = word_lists_checksum(&lists_model(&["pious"], &["crane"]));
+        assert_eq!(
+            checksum,
+            word_lists_checksum(&lists_model(&["pious"], &["crane"]))
+        );
+
+        // same words in the other list, or in other words
+        assert_ne!(
+            checksum,
+            word_lists_checksum(&lists_model(&["crane"], &["pious"]))
+        );
+        assert_ne!(
+            checksum,
+            word_lists_checksum(&lists_model(&["pio", "us"], &["crane"]))
+        );
+    }
+
+    #[test]
+    fn test_restore_finished_game() {
+        let mut model = Model {
+            max_attempts: 6,
+            ..Model::default()
+        };
+        SavedGame {
+            mode: GameMode::Daily,
+            puzzle_number: Some(100),
+            ..saved_game(&["pious"], &["piano", "pious"])
+        }
+        .restore(&mut model);
+
+        assert_eq!(
+            model.running_state,
+            RunningState::Over(GameResult::CorrectGuess)
+        );
+    }
+}
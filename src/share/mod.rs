@@ -0,0 +1,184 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::env;
+use std::io::{self, IsTerminal, Write};
+
+use crate::wordle;
+use crate::wordle::model::{
+    Board, GameMode, GameResult, LetterState, LetterStatus, Model, RunningState,
+};
+
+// share text of a finished game
+// Wordl 123 4/6*
+//
+// 🟨⬛⬛⬛⬛
+// 🟩🟩⬛🟨⬛
+// 🟩🟩🟩🟩🟩
+//
+// multi board games share the number of guesses taken by every board instead
+// Wordl Quordle 123 8/9
+//
+// 4️⃣8️⃣
+// 6️⃣🟥
+pub fn share_text(model: &Model) -> Option<String> {
+    let score = match &model.running_state {
+        RunningState::Over(GameResult::CorrectGuess) => model.attempts().to_string(),
+        RunningState::Over(GameResult::WrongGuess) => "X".into(),
+        _ => return None,
+    };
+
+    let mut title = String::from("Wordl");
+    match model.mode {
+        GameMode::Absurdle => title.push_str(" Absurdle"),
+        GameMode::Challenge => title.push_str(" Challenge"),
+        GameMode::Random | GameMode::Daily => {}
+    }
+    if let Some(name) = wordle::game::board_mode_name(model.boards.len()) {
+        title.push_str(&format!(" {}", name));
+    }
+    if let Some(number) = model.puzzle_number {
+        title.push_str(&format!(" {}", number));
+    }
+    title.push_str(&format!(" {}/{}", score, model.max_attempts));
+    // hard mode games are marked with an asterisk
+    if model.hard_mode {
+        title.push('*');
+    }
+
+    let rows: Vec<String> = match model.boards.as_slice() {
+        [board] => board.guesses.iter().map(|guess| emoji_row(guess)).collect(),
+        boards => boards
+            .chunks(wordle::game::board_columns(boards.len()))
+            .map(|row| row.iter().map(board_score).collect())
+            .collect(),
+    };
+
+    Some(format!("{}\n\n{}", title, rows.join("\n")))
+}
+
+// number of guesses a board was solved in as keycap emojis; 🟥 if not solved
+fn board_score(board: &Board) -> String {
+    if !board.is_solved() {
+        return "🟥".into();
+    }
+
+    board
+        .guesses
+        .len()
+        .to_string()
+        .chars()
+        .map(|digit| format!("{}\u{fe0f}\u{20e3}", digit))
+        .collect()
+}
+
+// 🟩 correct, 🟨 incorrect position, ⬛ not present
+pub fn emoji_row(guess: &[LetterStatus]) -> String {
+    guess
+        .iter()
+        .map(|x| match x.status {
+            LetterState::Correct => '🟩',
+            LetterState::Incorrect => '🟨',
+            LetterState::NotPresent | LetterState::Unknown => '⬛',
+        })
+        .collect()
+}
+
+// copies text to the system clipboard with OSC 52 terminal escape sequence
+// fails when stderr is not a terminal, or the terminal is known not to support OSC 52; share text
+// is printed on exit as a fallback then
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stderr = io::stderr();
+    let term = env::var("TERM").ok();
+    if !stderr.is_terminal() || !supports_osc52(term.as_deref()) {
+        return Err(io::ErrorKind::Unsupported.into());
+    }
+
+    write!(stderr, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+
+    stderr.flush()
+}
+
+// terminals that ignore OSC 52; dumb terminals and the linux console
+fn supports_osc52(term: Option<&str>) -> bool {
+    !matches!(term, None | Some("" | "dumb" | "linux"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::share::*;
+    use crate::wordle::utils::check;
+
+    #[test]
+    fn test_share_text() {
+        let mut model = Model {
+            boards: vec![Board {
+                guesses: vec![
+                    check("pious".into(), "piano".into()).unwrap(),
+                    check("pious".into(), "pious".into()).unwrap(),
+                ],
+                ..Board::new("pious")
+            }],
+            running_state: RunningState::Over(GameResult::CorrectGuess),
+            max_attempts: 6,
+            ..Model::default()
+        };
+
+        assert_eq!(
+            share_text(&model),
+            Some("Wordl 2/6\n\n🟩🟩⬛⬛🟨\n🟩🟩🟩🟩🟩".into())
+        );
+
+        // daily puzzle in hard mode
+        model.puzzle_number = Some(123);
+        model.hard_mode = true;
+        assert_eq!(
+            share_text(&model),
+            Some("Wordl 123 2/6*\n\n🟩🟩⬛⬛🟨\n🟩🟩🟩🟩🟩".into())
+        );
+
+        // lost game
+        model.running_state = RunningState::Over(GameResult::WrongGuess);
+        assert!(share_text(&model).unwrap().starts_with("Wordl 123 X/6*\n"));
+
+        // custom number of attempts
+        model.max_attempts = 10;
+        assert!(share_text(&model).unwrap().starts_with("Wordl 123 X/10*\n"));
+
+        // nothing to share while the game is on
+        model.running_state = RunningState::Waiting;
+        assert_eq!(share_text(&model), None);
+    }
+
+    #[test]
+    fn test_share_text_boards() {
+        let mut model = Model {
+            boards: ["pious", "crane", "smile", "ulcer"]
+                .into_iter()
+                .map(Board::new)
+                .collect(),
+            running_state: RunningState::Over(GameResult::WrongGuess),
+            max_attempts: 9,
+            ..Model::default()
+        };
+        for guess in ["pious", "crane", "smile"] {
+            let board_guesses = model.check_guess(guess).unwrap();
+            model.apply_guess(&board_guesses);
+        }
+
+        assert_eq!(
+            share_text(&model),
+            Some(
+                "Wordl Quordle X/9\n\n1\u{fe0f}\u{20e3}2\u{fe0f}\u{20e3}\n3\u{fe0f}\u{20e3}🟥"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_supports_osc52() {
+        assert!(supports_osc52(Some("xterm-256color")));
+        assert!(supports_osc52(Some("tmux-256color")));
+        assert!(!supports_osc52(Some("linux")));
+        assert!(!supports_osc52(Some("dumb")));
+        assert!(!supports_osc52(None));
+    }
+}
@@ -0,0 +1,505 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::storage;
+
+// finished game as stored in the stats file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRecord {
+    // comma separated wordles in multi board modes
+    pub wordle: String,
+    pub won: bool,
+    // number of guesses made
+    pub attempts: usize,
+    pub hard_mode: bool,
+    // number of guesses allowed; records from older versions are 6 attempt games
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: usize,
+    // number of boards; records from older versions are single board games
+    #[serde(default = "default_boards")]
+    pub boards: usize,
+    // number of letters in the wordle; records from older versions are 5 letter games
+    #[serde(default = "default_word_length")]
+    pub word_length: usize,
+    // daily puzzle number (only for daily mode)
+    pub puzzle_number: Option<usize>,
+    // rfc3339 timestamp of when the game was finished
+    pub played_at: String,
+}
+
+// finished speedrun as stored in the speedruns file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpeedrunRecord {
+    pub splits: Vec<SplitRecord>,
+    // time taken by all the wordles, in milliseconds
+    pub total_ms: u64,
+    pub solved: usize,
+    pub hard_mode: bool,
+    pub max_attempts: usize,
+    pub boards: usize,
+    pub word_length: usize,
+    // rfc3339 timestamp of when the run was finished
+    pub played_at: String,
+}
+
+// wordle of a speedrun
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitRecord {
+    // comma separated wordles in multi board modes
+    pub wordle: String,
+    pub won: bool,
+    pub attempts: usize,
+    pub time_ms: u64,
+}
+
+// finished survival run as stored in the survival file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SurvivalRecord {
+    // words solved in the run
+    pub words: Vec<String>,
+    // word the run ended with
+    pub failed_word: String,
+    pub hard_mode: bool,
+    // budget the run was started with
+    pub max_attempts: usize,
+    pub boards: usize,
+    pub word_length: usize,
+    // rfc3339 timestamp of when the run was finished
+    pub played_at: String,
+}
+
+// Game statistics
+// Finished games are appended to a json lines file under the XDG data directory
+#[derive(Debug, Default)]
+pub struct Stats {
+    // stats file; stats are kept only in memory if not present
+    path: Option<PathBuf>,
+    pub records: Vec<GameRecord>,
+    // speedruns file; stored next to the stats file
+    speedruns_path: Option<PathBuf>,
+    pub speedruns: Vec<SpeedrunRecord>,
+    // survival runs file; stored next to the stats file
+    survival_path: Option<PathBuf>,
+    pub survival_runs: Vec<SurvivalRecord>,
+}
+
+impl Stats {
+    // loads stats from the default stats file
+    pub fn load() -> Self {
+        let path = storage::data_file("stats.jsonl");
+        let speedruns_path = storage::data_file("speedruns.jsonl");
+        let survival_path = storage::data_file("survival.jsonl");
+
+        Stats {
+            records: load_records(path.as_deref()),
+            speedruns: load_records(speedruns_path.as_deref()),
+            survival_runs: load_records(survival_path.as_deref()),
+            path,
+            speedruns_path,
+            survival_path,
+        }
+    }
+
+    // records finished game and persists it to the stats file
+    pub fn record(&mut self, record: GameRecord) -> io::Result<()> {
+        self.records.push(record.clone());
+
+        match &self.path {
+            Some(path) => append_record(path, &record),
+            None => Ok(()),
+        }
+    }
+
+    // records finished speedrun and persists it to the speedruns file
+    pub fn record_speedrun(&mut self, record: SpeedrunRecord) -> io::Result<()> {
+        self.speedruns.push(record.clone());
+
+        match &self.speedruns_path {
+            Some(path) => append_record(path, &record),
+            None => Ok(()),
+        }
+    }
+
+    // records finished survival run and persists it to the survival file
+    pub fn record_survival(&mut self, record: SurvivalRecord) -> io::Result<()> {
+        self.survival_runs.push(record.clone());
+
+        match &self.survival_path {
+            Some(path) => append_record(path, &record),
+            None => Ok(()),
+        }
+    }
+
+    // most words solved in a survival run; only runs with the same starting budget, number of
+    // boards, word length and hard mode are compared
+    pub fn best_survival(
+        &self,
+        max_attempts: usize,
+        boards: usize,
+        word_length: usize,
+        hard_mode: bool,
+    ) -> usize {
+        self.survival_runs
+            .iter()
+            .filter(|x| {
+                x.max_attempts == max_attempts
+                    && x.boards == boards
+                    && x.word_length == word_length
+                    && x.hard_mode == hard_mode
+            })
+            .map(|x| x.words.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    // fastest speedrun with every wordle solved; only runs with the same number of wordles, attempt
+    // limit, number of boards, word length and hard mode are compared
+    pub fn best_speedrun(
+        &self,
+        wordles: usize,
+        max_attempts: usize,
+        boards: usize,
+        word_length: usize,
+        hard_mode: bool,
+    ) -> Option<u64> {
+        self.speedruns
+            .iter()
+            .filter(|x| {
+                x.splits.len() == wordles
+                    && x.solved == wordles
+                    && x.max_attempts == max_attempts
+                    && x.boards == boards
+                    && x.word_length == word_length
+                    && x.hard_mode == hard_mode
+            })
+            .map(|x| x.total_ms)
+            .min()
+    }
+
+    // games played with the same number of boards and word length; other games are counted
+    // separately
+    fn games(
+        &self,
+        boards: usize,
+        word_length: usize,
+    ) -> impl DoubleEndedIterator<Item = &GameRecord> {
+        self.records
+            .iter()
+            .filter(move |x| x.boards == boards && x.word_length == word_length)
+    }
+
+    pub fn played(&self, boards: usize, word_length: usize) -> usize {
+        self.games(boards, word_length).count()
+    }
+
+    pub fn win_percentage(&self, boards: usize, word_length: usize) -> usize {
+        let played = self.played(boards, word_length);
+        if played == 0 {
+            return 0;
+        }
+
+        let won = self.games(boards, word_length).filter(|x| x.won).count();
+
+        (won * 100 + played / 2) / played
+    }
+
+    // number of consecutive wins counting back from the latest game
+    pub fn current_streak(&self, boards: usize, word_length: usize) -> usize {
+        self.games(boards, word_length)
+            .rev()
+            .take_while(|x| x.won)
+            .count()
+    }
+
+    pub fn max_streak(&self, boards: usize, word_length: usize) -> usize {
+        let mut max_streak = 0;
+        let mut streak = 0;
+
+        for record in self.games(boards, word_length) {
+            if record.won {
+                streak += 1;
+                max_streak = max_streak.max(streak);
+            } else {
+                streak = 0;
+            }
+        }
+
+        max_streak
+    }
+
+    // number of games won in 1 to max attempts; only games played with the same attempt limit,
+    // number of boards and word length are counted
+    pub fn guess_distribution(
+        &self,
+        max_attempts: usize,
+        boards: usize,
+        word_length: usize,
+    ) -> Vec<usize> {
+        let mut distribution = vec![0; max_attempts];
+
+        for record in self
+            .games(boards, word_length)
+            .filter(|x| x.won && x.max_attempts == max_attempts)
+        {
+            if let Some(count) = distribution.get_mut(record.attempts.wrapping_sub(1)) {
+                *count += 1;
+            }
+        }
+
+        distribution
+    }
+}
+
+fn default_max_attempts() -> usize {
+    crate::wordle::game::DEFAULT_ATTEMPTS
+}
+
+fn default_boards() -> usize {
+    1
+}
+
+fn default_word_length() -> usize {
+    crate::wordle::utils::WORD_LENGTH
+}
+
+fn load_records<T: DeserializeOwned>(path: Option<&Path>) -> Vec<T> {
+    path.and_then(|path| fs::read_to_string(path).ok())
+        .map(|data| parse_records(&data))
+        .unwrap_or_default()
+}
+
+fn append_record<T: Serialize>(path: &Path, record: &T) -> io::Result<()> {
+    storage::create_parent_dir(path)?;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(record)?;
+    writeln!(file, "{}", line)
+}
+
+// one record per line; lines that cannot be parsed are skipped
+fn parse_records<T: DeserializeOwned>(data: &str) -> Vec<T> {
+    data.lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stats::*;
+
+    fn game(won: bool, attempts: usize) -> GameRecord {
+        GameRecord {
+            wordle: "pious".into(),
+            word_length: 5,
+            won,
+            attempts,
+            hard_mode: false,
+            max_attempts: 6,
+            boards: 1,
+            puzzle_number: None,
+            played_at: "2024-01-01T00:00:00+00:00".into(),
+        }
+    }
+
+    #[test]
+    fn test_empty_stats() {
+        let stats = Stats::default();
+
+        assert_eq!(stats.played(1, 5), 0);
+        assert_eq!(stats.win_percentage(1, 5), 0);
+        assert_eq!(stats.current_streak(1, 5), 0);
+        assert_eq!(stats.max_streak(1, 5), 0);
+        assert_eq!(stats.guess_distribution(6, 1, 5), vec![0; 6]);
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = Stats::default();
+        let games = vec![
+            game(true, 3),
+            game(true, 4),
+            game(true, 4),
+            game(false, 6),
+            game(true, 2),
+            game(true, 6),
+        ];
+        for record in games {
+            stats.record(record).unwrap();
+        }
+
+        assert_eq!(stats.played(1, 5), 6);
+        assert_eq!(stats.win_percentage(1, 5), 83);
+        assert_eq!(stats.current_streak(1, 5), 2);
+        assert_eq!(stats.max_streak(1, 5), 3);
+        assert_eq!(stats.guess_distribution(6, 1, 5), vec![0, 1, 1, 2, 0, 1]);
+
+        // games with other attempt limits are bucketed separately
+        stats
+            .record(GameRecord {
+                max_attempts: 3,
+                ..game(true, 2)
+            })
+            .unwrap();
+        assert_eq!(stats.guess_distribution(3, 1, 5), vec![0, 1, 0]);
+        assert_eq!(stats.guess_distribution(6, 1, 5), vec![0, 1, 1, 2, 0, 1]);
+
+        // so are multi board games
+        stats
+            .record(GameRecord {
+                boards: 2,
+                ..game(true, 6)
+            })
+            .unwrap();
+        assert_eq!(stats.guess_distribution(6, 2, 5), vec![0, 0, 0, 0, 0, 1]);
+        assert_eq!(stats.guess_distribution(6, 1, 5), vec![0, 1, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_stats_word_length() {
+        let mut stats = Stats::default();
+        for record in [
+            game(true, 3),
+            GameRecord {
+                word_length: 4,
+                ..game(true, 3)
+            },
+            game(false, 6),
+            GameRecord {
+                word_length: 4,
+                ..game(true, 5)
+            },
+            GameRecord {
+                boards: 2,
+                ..game(true, 4)
+            },
+        ] {
+            stats.record(record).unwrap();
+        }
+
+        // games with other word lengths or number of boards are counted separately
+        assert_eq!(stats.played(1, 5), 2);
+        assert_eq!(stats.win_percentage(1, 5), 50);
+        assert_eq!(stats.current_streak(1, 5), 0);
+        assert_eq!(stats.max_streak(1, 5), 1);
+        assert_eq!(stats.guess_distribution(6, 1, 5), vec![0, 0, 1, 0, 0, 0]);
+
+        assert_eq!(stats.played(1, 4), 2);
+        assert_eq!(stats.win_percentage(1, 4), 100);
+        assert_eq!(stats.current_streak(1, 4), 2);
+        assert_eq!(stats.max_streak(1, 4), 2);
+        assert_eq!(stats.guess_distribution(6, 1, 4), vec![0, 0, 1, 0, 1, 0]);
+
+        assert_eq!(stats.played(2, 5), 1);
+        assert_eq!(stats.current_streak(2, 5), 1);
+        assert_eq!(stats.played(1, 8), 0);
+    }
+
+    fn speedrun(wordles: usize, solved: usize, total_ms: u64) -> SpeedrunRecord {
+        let split = SplitRecord {
+            wordle: "pious".into(),
+            won: true,
+            attempts: 3,
+            time_ms: total_ms / wordles as u64,
+        };
+        SpeedrunRecord {
+            splits: vec![split; wordles],
+            total_ms,
+            solved,
+            hard_mode: false,
+            max_attempts: 6,
+            boards: 1,
+            word_length: 5,
+            played_at: "2024-01-01T00:00:00+00:00".into(),
+        }
+    }
+
+    #[test]
+    fn test_best_speedrun() {
+        let mut stats = Stats::default();
+        assert_eq!(stats.best_speedrun(3, 6, 1, 5, false), None);
+
+        for record in [
+            speedrun(3, 3, 90_000),
+            speedrun(3, 3, 75_000),
+            // runs with a failed wordle do not count
+            speedrun(3, 2, 60_000),
+            speedrun(5, 5, 50_000),
+        ] {
+            stats.record_speedrun(record).unwrap();
+        }
+
+        assert_eq!(stats.best_speedrun(3, 6, 1, 5, false), Some(75_000));
+        assert_eq!(stats.best_speedrun(5, 6, 1, 5, false), Some(50_000));
+        assert_eq!(stats.best_speedrun(3, 6, 2, 5, false), None);
+
+        // same runs with other word lengths or in hard mode are compared separately
+        for (word_length, hard_mode) in [(4, false), (5, true)] {
+            stats
+                .record_speedrun(SpeedrunRecord {
+                    word_length,
+                    hard_mode,
+                    ..speedrun(3, 3, 30_000)
+                })
+                .unwrap();
+        }
+        assert_eq!(stats.best_speedrun(3, 6, 1, 5, false), Some(75_000));
+        assert_eq!(stats.best_speedrun(3, 6, 1, 4, false), Some(30_000));
+        assert_eq!(stats.best_speedrun(3, 6, 1, 8, false), None);
+        assert_eq!(stats.best_speedrun(3, 6, 1, 5, true), Some(30_000));
+    }
+
+    #[test]
+    fn test_best_survival() {
+        let mut stats = Stats::default();
+        assert_eq!(stats.best_survival(6, 1, 5, false), 0);
+
+        for (words, max_attempts, word_length, hard_mode) in [
+            (3, 6, 5, false),
+            (7, 8, 5, false),
+            (5, 6, 5, false),
+            // other word lengths and hard mode runs are compared separately
+            (9, 6, 4, false),
+            (8, 6, 5, true),
+        ] {
+            stats
+                .record_survival(SurvivalRecord {
+                    words: vec!["pious".into(); words],
+                    failed_word: "crane".into(),
+                    hard_mode,
+                    max_attempts,
+                    boards: 1,
+                    word_length,
+                    played_at: "2024-01-01T00:00:00+00:00".into(),
+                })
+                .unwrap();
+        }
+
+        assert_eq!(stats.best_survival(6, 1, 5, false), 5);
+        assert_eq!(stats.best_survival(8, 1, 5, false), 7);
+        assert_eq!(stats.best_survival(6, 2, 5, false), 0);
+        assert_eq!(stats.best_survival(6, 1, 4, false), 9);
+        assert_eq!(stats.best_survival(6, 1, 5, true), 8);
+        assert_eq!(stats.best_survival(6, 1, 8, false), 0);
+    }
+
+    #[test]
+    fn test_parse_records() {
+        let line = serde_json::to_string(&game(true, 3)).unwrap();
+        let data = format!("{}\n\nnot json\n{}\n", line, line);
+
+        assert_eq!(
+            parse_records::<GameRecord>(&data),
+            vec![game(true, 3), game(true, 3)]
+        );
+
+        // records without word length, attempts and boards are 5 letter, 6 attempt, single board games
+        let old_line = r#"{"wordle":"pious","won":true,"attempts":3,"hard_mode":false,"puzzle_number":null,"played_at":"2024-01-01T00:00:00+00:00"}"#;
+        let records: Vec<GameRecord> = parse_records(old_line);
+        assert_eq!(records[0].word_length, 5);
+        assert_eq!(records[0].max_attempts, 6);
+        assert_eq!(records[0].boards, 1);
+    }
+}
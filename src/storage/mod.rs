@@ -0,0 +1,21 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// file under wordl data directory; $XDG_DATA_HOME/wordl/<name>
+pub fn data_file(name: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("wordl").join(name))
+}
+
+// file under wordl config directory; $XDG_CONFIG_HOME/wordl/<name>
+pub fn config_file(name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("wordl").join(name))
+}
+
+// makes sure parent directory of the file is present
+pub fn create_parent_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
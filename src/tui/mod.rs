@@ -64,6 +64,11 @@ impl Tui {
         Ok(())
     }
 
+    // size of the terminal; needed to find what was clicked on
+    pub fn size(&self) -> Result<ratatui::layout::Rect> {
+        Ok(self.terminal.size()?)
+    }
+
     // draw the terminal interface
     pub fn draw(&mut self, model: &mut Model) -> Result<()> {
         self.terminal.draw(|frame| ui::view(model, frame))?;
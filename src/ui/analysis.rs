@@ -0,0 +1,109 @@
+use ratatui::widgets::block::Title;
+use ratatui::{prelude::*, widgets::*};
+
+use crate::wordle::model::Model;
+
+// post game analysis modal drawn on top of the game
+pub fn draw(frame: &mut Frame, rect: Rect, model: &mut Model) {
+    let width = 64.min(rect.width);
+    // one row per guess
+    let rows = model
+        .analysis
+        .as_ref()
+        .map_or(1, |analysis| analysis.guesses.len()) as u16;
+    let height = (10 + rows).min(rect.height);
+    let area = Rect {
+        x: rect.left() + (rect.width - width) / 2,
+        y: rect.top() + (rect.height - height) / 2,
+        width,
+        height,
+    };
+
+    let text_style = Style::new().fg(Color::Rgb(255, 255, 255));
+    let label_style = Style::new().fg(Color::Rgb(189, 189, 189));
+
+    let mut lines = vec![Line::from("")];
+
+    match &model.analysis {
+        None => lines.push(Line::from(Span::styled("Thinking ...", label_style))),
+        Some(analysis) => {
+            lines.push(Line::from(vec![
+                Span::styled(format!("Skill {}", analysis.skill()), text_style.bold()),
+                Span::styled(" · ", label_style),
+                Span::styled(format!("Luck {}", analysis.luck()), text_style.bold()),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(
+                Line::from(Span::styled(
+                    format!(
+                        "  # {:<8} {:>13} {:>6}   {:<8} {:>5} {:>5}",
+                        "GUESS", "WORDS LEFT", "BITS", "BEST", "SKILL", "LUCK"
+                    ),
+                    label_style,
+                ))
+                .alignment(Alignment::Left),
+            );
+
+            for (index, guess) in analysis.guesses.iter().enumerate() {
+                // best guess is shown only if it was a different word
+                let best = if guess.best.word == guess.word {
+                    "-".to_string()
+                } else {
+                    guess.best.word.to_uppercase()
+                };
+
+                lines.push(
+                    Line::from(vec![
+                        Span::styled(format!(" {:>2} ", index + 1), label_style),
+                        Span::styled(
+                            format!("{:<8} ", guess.word.to_uppercase()),
+                            text_style.bold(),
+                        ),
+                        Span::styled(
+                            format!(
+                                "{:>6} → {:<4} {:>6.2}   {:<8} ",
+                                guess.candidates_before,
+                                guess.candidates_after,
+                                guess.entropy,
+                                best
+                            ),
+                            text_style,
+                        ),
+                        Span::styled(format!("{:>5} ", guess.skill), text_style.bold()),
+                        Span::styled(format!("{:>5}", guess.luck), text_style.bold()),
+                    ])
+                    .alignment(Alignment::Left),
+                );
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Bits: expected information. Skill and luck are out of 100.",
+        label_style,
+    )));
+    let keys = model.settings.key_bindings;
+    lines.push(Line::from(Span::styled(
+        format!(
+            "{} to close. {} for new wordle.",
+            keys.analysis, keys.new_game
+        ),
+        label_style,
+    )));
+
+    let block = Block::new()
+        .title(Title::from(" ANALYSIS ").alignment(Alignment::Center))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(Color::Cyan))
+        .style(Style::new().bg(Color::Rgb(0, 0, 0)));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Center),
+        area,
+    );
+}
@@ -1,16 +1,61 @@
 // use ratatui::widgets::block::Title;
+use crate::wordle;
+use crate::wordle::animation::Effect;
 use crate::wordle::model::{LetterState, LetterStatus, Model};
+use crate::wordle::settings::Theme;
 use ratatui::{prelude::*, widgets::*};
+use std::time::Instant;
 
 use super::get_grid_color;
 
+// multi board letters are 3 columns wide and 1 line high, so that all the boards fit on screen
+const COMPACT_WIDTH: u16 = 3;
+// space between the boards
+const BOARD_GAP: u16 = 2;
+// single board letters
+const CELL_WIDTH: u16 = 5;
+const CELL_HEIGHT: u16 = 3;
+// highlight of the letter being edited
+const FOCUS_COLOR: Color = Color::Cyan;
+
+// lines needed by the grid, including the help text
+pub fn height(model: &Model) -> u16 {
+    let rows = model.max_attempts as u16;
+
+    match model.boards.len() {
+        // grid rows are 3 lines high; 1 line above the grid and 1 line for the help text
+        1 => rows * 3 + 2,
+        boards => {
+            let board_rows = boards.div_ceil(wordle::game::board_columns(boards)) as u16;
+            board_rows * (rows + 1) + 2
+        }
+    }
+}
+
 pub fn draw(frame: &mut Frame, rect: Rect, model: &mut Model) {
-    // draw empty grid
-    for row in 0..=5 {
-        for column in 0..5 {
+    if model.boards.len() > 1 {
+        draw_boards(frame, rect, model);
+        return;
+    }
+    let Some(board) = model.boards.first() else {
+        return;
+    };
+
+    let columns = model.word_length();
+    let theme = model.settings.theme;
+    let active_row = board.guesses.len();
+    // rejected guess shakes
+    let shift = model.shake_offset();
+    let now = Instant::now();
+
+    // draw empty grid; active row is drawn with the active guess
+    for row in (active_row + 1)..model.max_attempts {
+        for column in 0..columns {
             render(
                 frame,
                 rect,
+                columns,
+                theme,
                 Grid {
                     row,
                     column,
@@ -18,35 +63,190 @@ pub fn draw(frame: &mut Frame, rect: Rect, model: &mut Model) {
                         letter: ' ',
                         status: LetterState::Unknown,
                     },
+                    shift: 0,
+                    effect: None,
                 },
             );
         }
     }
 
-    for (row, guess) in model.guesses.iter().enumerate() {
-        for (column, guess_status) in guess.into_iter().enumerate() {
+    for (row, guess) in board.guesses.iter().enumerate() {
+        for (column, guess_status) in guess.iter().enumerate() {
             let grid = Grid {
                 row,
                 column,
                 letter_status: guess_status.clone(),
+                shift: 0,
+                effect: model.animations.cell(0, row, column, now),
             };
-            render(frame, rect, grid);
+            render(frame, rect, columns, theme, grid);
         }
     }
 
     // render active guess
-    if model.active_guess.len() > 0 {
-        let row = model.guesses.len();
-        for (column, letter) in model.active_guess.chars().enumerate() {
+    if active_row < model.max_attempts {
+        for column in 0..columns {
             let grid = Grid {
-                row,
+                row: active_row,
                 column,
                 letter_status: LetterStatus {
-                    letter,
+                    letter: model.active_guess.chars().nth(column).unwrap_or(' '),
                     status: LetterState::Unknown,
                 },
+                shift,
+                effect: model.animations.cell(0, active_row, column, now),
             };
-            render(frame, rect, grid);
+            render(frame, rect, columns, theme, grid);
+        }
+    }
+
+    // letter being edited is outlined
+    if let Some(column) = model.cursor {
+        let area = shifted(cell_area(rect, columns, active_row, column), shift);
+        if fits(area, rect) {
+            frame.render_widget(
+                Block::new()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::new().fg(FOCUS_COLOR)),
+                area,
+            );
+        }
+    }
+}
+
+// letter of the active guess at the clicked position
+pub fn cell_at(rect: Rect, model: &Model, column: u16, row: u16) -> Option<usize> {
+    let position = Rect {
+        x: column,
+        y: row,
+        width: 1,
+        height: 1,
+    };
+
+    (0..model.word_length()).find(|&letter| {
+        if model.boards.len() == 1 {
+            let active_row = model.attempts();
+            cell_area(rect, model.word_length(), active_row, letter).intersects(position)
+        } else {
+            // active row of any board that is not solved
+            model
+                .boards
+                .iter()
+                .enumerate()
+                .filter(|(_, board)| !board.is_solved())
+                .any(|(index, board)| {
+                    compact_cell_area(rect, model, index, board.guesses.len(), letter)
+                        .intersects(position)
+                })
+        }
+    })
+}
+
+// area of a single board letter; columns: number of letters in a row, used to center the grid
+fn cell_area(rect: Rect, columns: usize, row: usize, column: usize) -> Rect {
+    let offset: u16 =
+        ((rect.width - rect.left()) / 2).saturating_sub(columns as u16 * CELL_WIDTH / 2);
+
+    Rect {
+        x: rect.left() + (column as u16) * CELL_WIDTH + offset,
+        y: rect.top() + (row as u16 * CELL_HEIGHT) + 1,
+        width: CELL_WIDTH,
+        height: CELL_HEIGHT,
+    }
+}
+
+// area of a multi board letter
+fn compact_cell_area(rect: Rect, model: &Model, board: usize, row: usize, column: usize) -> Rect {
+    let columns = wordle::game::board_columns(model.boards.len()) as u16;
+    let board_width = model.word_length() as u16 * COMPACT_WIDTH;
+    let board_height = model.max_attempts as u16 + 1;
+    let total_width = columns * board_width + (columns - 1) * BOARD_GAP;
+    let left = rect.left() + rect.width.saturating_sub(total_width) / 2;
+
+    let x = left + (board as u16 % columns) * (board_width + BOARD_GAP);
+    let y = rect.top() + 1 + (board as u16 / columns) * board_height;
+
+    Rect {
+        x: x + column as u16 * COMPACT_WIDTH,
+        y: y + row as u16,
+        width: COMPACT_WIDTH,
+        height: 1,
+    }
+}
+
+// area moved sideways by the shift (in columns)
+fn shifted(area: Rect, shift: i16) -> Rect {
+    Rect {
+        x: area.x.saturating_add_signed(shift),
+        ..area
+    }
+}
+
+// cells that do not fit in a small terminal are not drawn
+fn fits(area: Rect, rect: Rect) -> bool {
+    area.bottom() <= rect.bottom() && area.right() <= rect.right()
+}
+
+// multi board modes; boards are tiled in rows with compact letters
+fn draw_boards(frame: &mut Frame, rect: Rect, model: &Model) {
+    // rejected guess shakes
+    let shift = model.shake_offset();
+    let now = Instant::now();
+
+    for (index, board) in model.boards.iter().enumerate() {
+        let active_row = (!board.is_solved()).then_some(board.guesses.len());
+
+        for row in 0..model.max_attempts {
+            for column in 0..model.word_length() {
+                let letter_status = match board.guesses.get(row) {
+                    // compact letters do not flip; status is shown once the flip is halfway
+                    Some(guess) if !model.is_revealed(index, row, column, now) => LetterStatus {
+                        letter: guess[column].letter,
+                        status: LetterState::Unknown,
+                    },
+                    Some(guess) => guess[column].clone(),
+                    // active guess is shown only on the boards that are not solved
+                    None if Some(row) == active_row => LetterStatus {
+                        letter: model.active_guess.chars().nth(column).unwrap_or(' '),
+                        status: LetterState::Unknown,
+                    },
+                    None => LetterStatus {
+                        letter: ' ',
+                        status: LetterState::Unknown,
+                    },
+                };
+
+                let mut area = compact_cell_area(rect, model, index, row, column);
+                if Some(row) == active_row {
+                    area = shifted(area, shift);
+                }
+                if !fits(area, rect) {
+                    continue;
+                }
+
+                // letter being edited is highlighted
+                let is_focused = Some(row) == active_row && model.cursor == Some(column);
+                let bg = if is_focused {
+                    FOCUS_COLOR
+                } else {
+                    get_grid_color(model.settings.theme, letter_status.status)
+                };
+
+                // typed letter pops; compact letters have no border, so the colors are swapped
+                let mut style = Style::new().bg(bg).fg(Color::Rgb(255, 255, 255)).bold();
+                if let Some((Effect::Pop, _)) = model.animations.cell(index, row, column, now) {
+                    style = style.reversed();
+                }
+
+                let letter = letter_status.letter.to_uppercase().to_string();
+                frame.render_widget(
+                    Paragraph::new(letter)
+                        .style(style)
+                        .alignment(Alignment::Center),
+                    area,
+                );
+            }
         }
     }
 }
@@ -55,30 +255,55 @@ struct Grid {
     row: usize,
     column: usize,
     letter_status: LetterStatus,
+    // sideways offset (in columns); active row shakes
+    shift: i16,
+    // running animation and its progress
+    effect: Option<(Effect, f32)>,
 }
 
-fn render(frame: &mut Frame, rect: Rect, grid: Grid) {
-    let width = 5;
-    let height = 3;
-    let row = grid.row;
-    let column = grid.column;
-
+// columns: number of letters in a row; used to center the grid
+fn render(frame: &mut Frame, rect: Rect, columns: usize, theme: Theme, grid: Grid) {
     let letter = grid.letter_status.letter.to_uppercase().to_string();
-    let offset: u16 = u16::from((rect.width - rect.left()) / 2) - 12;
-    let x = rect.left() + u16::from(column as u16) * width + offset;
-    let area = Rect {
-        x,
-        y: rect.top() + (row as u16 * height) + 1,
-        width,
-        height,
-    };
+    let mut area = shifted(cell_area(rect, columns, grid.row, grid.column), grid.shift);
+    if !fits(area, rect) {
+        return;
+    }
+
+    let mut status = grid.letter_status.status;
+    let mut border_color = Color::Rgb(0, 0, 0);
+    match grid.effect {
+        // tile shrinks to a line and grows back; status is shown from the halfway point
+        Some((Effect::Flip, progress)) => {
+            if progress < 0.5 {
+                status = LetterState::Unknown;
+            }
+            let scale = (1.0 - 2.0 * progress).abs();
+            let height = ((CELL_HEIGHT as f32 * scale).round() as u16).max(1);
+            if height < CELL_HEIGHT {
+                area.y += (CELL_HEIGHT - height) / 2;
+                area.height = height;
+                // tile seen edge on; letter is not visible
+                frame.render_widget(
+                    Block::new().style(Style::new().bg(get_grid_color(theme, status))),
+                    area,
+                );
+                return;
+            }
+        }
+        Some((Effect::Pop, _)) => border_color = Color::Rgb(255, 255, 255),
+        // tile is up in the air for the middle of the bounce; line above the grid is the status
+        Some((Effect::Bounce, progress)) if (0.2..0.8).contains(&progress) => {
+            area.y = area.y.saturating_sub(1).max(rect.top() + 1);
+        }
+        _ => {}
+    }
 
-    let bg = get_grid_color(grid.letter_status.status);
+    let bg = get_grid_color(theme, status);
 
     let block = Block::new()
         .borders(Borders::ALL)
         .border_type(BorderType::QuadrantOutside)
-        .border_style(Style::new().fg(Color::Rgb(0, 0, 0)))
+        .border_style(Style::new().fg(border_color))
         // .padding(Padding::new(1, 1, 1, 1))
         // .style(Style::new().white().on_black().bg(Color::Rgb(0, 0, 0)));
         .style(
@@ -91,7 +316,7 @@ fn render(frame: &mut Frame, rect: Rect, grid: Grid) {
         );
 
     frame.render_widget(
-        Paragraph::new(format!("{}", letter.to_string()))
+        Paragraph::new(letter.to_string())
             .block(block)
             .style(
                 Style::new()
@@ -0,0 +1,73 @@
+use ratatui::widgets::block::Title;
+use ratatui::{prelude::*, widgets::*};
+
+use crate::wordle::model::Model;
+
+// maximum number of remaining candidates listed in the hints
+const MAX_LISTED_CANDIDATES: usize = 5;
+
+// solver hints panel drawn on the right side of the grid
+pub fn draw(frame: &mut Frame, rect: Rect, model: &mut Model) {
+    let width = 30.min(rect.width);
+    let height = 14.min(rect.height);
+    let area = Rect {
+        x: rect.right() - width,
+        y: rect.top() + 1,
+        width,
+        height,
+    };
+
+    let text_style = Style::new().fg(Color::Rgb(255, 255, 255));
+    let label_style = Style::new().fg(Color::Rgb(189, 189, 189));
+
+    let mut lines = vec![Line::from("")];
+
+    match &model.hints {
+        None => lines.push(Line::from(Span::styled("Thinking ...", label_style))),
+        Some(hints) => {
+            lines.push(Line::from(Span::styled(
+                format!("{} possible words left", hints.candidates.len()),
+                text_style.bold(),
+            )));
+
+            if hints.candidates.len() <= MAX_LISTED_CANDIDATES {
+                lines.push(Line::from(Span::styled(
+                    hints.candidates.join(", ").to_uppercase(),
+                    label_style,
+                )));
+            }
+
+            lines.push(Line::from(""));
+
+            for (index, suggestion) in hints.suggestions.iter().enumerate() {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{}. ", index + 1), label_style),
+                    Span::styled(suggestion.word.to_uppercase(), text_style.bold()),
+                    Span::styled(format!(" {:>5.2} bits", suggestion.entropy), label_style),
+                ]));
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("Press {} to close", model.settings.key_bindings.hints),
+        label_style,
+    )));
+
+    let block = Block::new()
+        .title(Title::from(" HINTS ").alignment(Alignment::Center))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(Color::Cyan))
+        .style(Style::new().bg(Color::Rgb(0, 0, 0)));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        area,
+    );
+}
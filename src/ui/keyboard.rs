@@ -2,9 +2,43 @@ use ratatui::widgets::block::Title;
 use ratatui::{prelude::*, widgets::*};
 
 use super::get_grid_color;
-use crate::wordle::model::{KeyboardHints, LetterState};
+use crate::wordle::model::{Board, LetterState, Message, Model};
+use crate::wordle::settings::{KeyboardLayout, Theme};
 
-pub fn draw(frame: &mut Frame, rect: Rect, hints: &mut KeyboardHints) {
+const KEY_WIDTH: u16 = 5;
+const KEY_HEIGHT: u16 = 3;
+// enter and erase keys are on both sides of the last row
+const ACTION_KEY_WIDTH: u16 = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Key {
+    Letter(char),
+    Enter,
+    Erase,
+}
+
+impl Key {
+    fn label(&self) -> String {
+        match self {
+            Key::Letter(letter) => letter.to_string(),
+            Key::Enter => "ENTER".into(),
+            Key::Erase => "⌫".into(),
+        }
+    }
+
+    // message sent when the key is clicked
+    pub fn message(&self) -> Message {
+        match self {
+            Key::Letter(letter) => Message::Listen(*letter),
+            Key::Enter => Message::CalculateStart,
+            Key::Erase => Message::Erase,
+        }
+    }
+}
+
+pub fn draw(frame: &mut Frame, rect: Rect, model: &Model) {
+    let boards = &model.boards;
+    let theme = model.settings.theme;
     let github_link = String::from("https://github.com/palerdot/wordl-rs");
     let version = env!("CARGO_PKG_VERSION");
 
@@ -25,52 +59,143 @@ pub fn draw(frame: &mut Frame, rect: Rect, hints: &mut KeyboardHints) {
 
     frame.render_widget(master_block, rect);
 
-    let keyboard_size = (10 * 5) / 2; // 10 letters max and width 5
+    for (key, area) in key_areas(rect, model.settings.keyboard_layout) {
+        // keys that do not fit in a small terminal are not drawn
+        if area.right() > frame.size().right() || area.bottom() > frame.size().bottom() {
+            continue;
+        }
+
+        // single board colors; multi board keys are split after drawing
+        let bg = match key {
+            Key::Letter(letter) => get_grid_color(
+                theme,
+                boards
+                    .first()
+                    .map_or(LetterState::Unknown, |board| letter_state(board, letter)),
+            ),
+            Key::Enter | Key::Erase => get_grid_color(theme, LetterState::Unknown),
+        };
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .border_type(BorderType::QuadrantOutside)
+            .border_style(Style::new().fg(Color::Rgb(0, 0, 0)))
+            .style(Style::new().white().on_black().bg(bg).bold());
 
-    let letters = vec!["qwertyuiop".chars(), "asdfghjkl".chars(), "zxcvbnm".chars()];
+        frame.render_widget(
+            Paragraph::new(key.label())
+                .block(block)
+                .style(
+                    Style::new()
+                        .white()
+                        .on_black()
+                        .bg(bg)
+                        .fg(Color::Rgb(255, 255, 255)),
+                )
+                .alignment(Alignment::Center),
+            area,
+        );
+
+        match key {
+            Key::Letter(letter) if boards.len() > 1 => {
+                split_key_colors(frame, area, letter, boards, theme)
+            }
+            _ => {}
+        }
+    }
+}
 
-    let width = 5;
-    let height = 3;
+// area of every key; same geometry is used to draw the keys and to find the clicked key
+pub fn key_areas(rect: Rect, layout: KeyboardLayout) -> Vec<(Key, Rect)> {
+    let keyboard_size = (10 * KEY_WIDTH) / 2; // 10 letters max
+    let offset: u16 = ((rect.width - rect.left()) / 2).saturating_sub(keyboard_size);
+    let letters = layout.rows();
 
+    let mut keys = vec![];
     for (row_index, row) in letters.iter().enumerate() {
-        for (index, letter) in row.clone().enumerate() {
-            let offset: u16 = u16::from((rect.width - rect.left()) / 2) - keyboard_size;
-            let x = rect.left() + u16::from(index as u16) * width + offset + (row_index as u16 * 3);
+        // rows are shifted right by 3 columns each
+        let left = rect.left() + offset + (row_index as u16 * 3);
+        let y = rect.top() + (row_index as u16 * KEY_HEIGHT + 1);
+
+        for (index, letter) in row.chars().enumerate() {
             let area = Rect {
-                x,
-                y: rect.top() + (row_index as u16 * height + 1),
-                width,
-                height,
+                x: left + (index as u16) * KEY_WIDTH,
+                y,
+                width: KEY_WIDTH,
+                height: KEY_HEIGHT,
             };
+            keys.push((Key::Letter(letter), area));
+        }
 
-            let letter_hint = hints.get(&letter);
-            let letter_status = if letter_hint.is_some() {
-                letter_hint.unwrap().clone()
-            } else {
-                LetterState::Unknown
-            };
+        if row_index == letters.len() - 1 {
+            let right = left + row.len() as u16 * KEY_WIDTH;
+            keys.push((
+                Key::Enter,
+                Rect {
+                    x: left.saturating_sub(ACTION_KEY_WIDTH),
+                    y,
+                    width: ACTION_KEY_WIDTH,
+                    height: KEY_HEIGHT,
+                },
+            ));
+            keys.push((
+                Key::Erase,
+                Rect {
+                    x: right,
+                    y,
+                    width: ACTION_KEY_WIDTH,
+                    height: KEY_HEIGHT,
+                },
+            ));
+        }
+    }
+
+    keys
+}
+
+// key at the clicked position
+pub fn key_at(rect: Rect, layout: KeyboardLayout, column: u16, row: u16) -> Option<Key> {
+    let position = Rect {
+        x: column,
+        y: row,
+        width: 1,
+        height: 1,
+    };
+
+    key_areas(rect, layout)
+        .into_iter()
+        .find(|(_, area)| area.intersects(position))
+        .map(|(key, _)| key)
+}
+
+fn letter_state(board: &Board, letter: char) -> LetterState {
+    board
+        .keyboard_hints
+        .get(&letter)
+        .cloned()
+        .unwrap_or_default()
+}
+
+// multi board modes; key is split into one segment per board, colored with the hint of that board
+// 2 boards are split left and right; 4 and 8 boards are split in 2 rows
+fn split_key_colors(frame: &mut Frame, area: Rect, letter: char, boards: &[Board], theme: Theme) {
+    let (segment_columns, segment_rows) = if boards.len() > 2 {
+        (boards.len() / 2, 2)
+    } else {
+        (boards.len(), 1)
+    };
+
+    let buffer = frame.buffer_mut();
+    for y in area.top()..area.bottom().min(buffer.area.bottom()) {
+        for x in area.left()..area.right().min(buffer.area.right()) {
+            let segment_row = usize::from(y - area.top()) * segment_rows / usize::from(area.height);
+            let segment_column =
+                usize::from(x - area.left()) * segment_columns / usize::from(area.width);
+            let board = &boards[segment_row * segment_columns + segment_column];
 
-            let bg = get_grid_color(letter_status);
-
-            let block = Block::new()
-                .borders(Borders::ALL)
-                .border_type(BorderType::QuadrantOutside)
-                .border_style(Style::new().fg(Color::Rgb(0, 0, 0)))
-                .style(Style::new().white().on_black().bg(bg).bold());
-
-            frame.render_widget(
-                Paragraph::new(format!("{}", letter.to_string()))
-                    .block(block)
-                    .style(
-                        Style::new()
-                            .white()
-                            .on_black()
-                            .bg(bg)
-                            .fg(Color::Rgb(255, 255, 255)),
-                    )
-                    .alignment(Alignment::Center),
-                area,
-            );
+            buffer
+                .get_mut(x, y)
+                .set_bg(get_grid_color(theme, letter_state(board, letter)));
         }
     }
 }
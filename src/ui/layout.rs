@@ -1,11 +1,19 @@
 use ratatui::prelude::*;
 use std::rc::Rc;
 
-pub fn master_layout(frame: &mut Frame) -> Rc<[ratatui::layout::Rect]> {
-    let size = frame.size();
+// keyboard hints need 3 rows of keys
+const KEYBOARD_HEIGHT: u16 = 11;
 
-    let constraints = if size.height >= 31 {
-        vec![Constraint::Percentage(66), Constraint::Percentage(34)]
+// size: terminal size; grid_height: lines needed by the grid, including the help text
+pub fn master_layout(size: Rect, grid_height: u16) -> Rc<[ratatui::layout::Rect]> {
+    let constraints = if size.height >= grid_height + KEYBOARD_HEIGHT {
+        // keyboard gets about a third of the screen, as long as the grid fits
+        let keyboard_height =
+            (size.height * 34 / 100).clamp(KEYBOARD_HEIGHT, size.height - grid_height);
+        vec![
+            Constraint::Length(size.height - keyboard_height),
+            Constraint::Length(keyboard_height),
+        ]
     } else {
         vec![Constraint::Percentage(100)]
     };
@@ -13,5 +21,5 @@ pub fn master_layout(frame: &mut Frame) -> Rc<[ratatui::layout::Rect]> {
     Layout::default()
         .direction(Direction::Vertical)
         .constraints(constraints)
-        .split(frame.size())
+        .split(size)
 }
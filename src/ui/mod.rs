@@ -1,17 +1,32 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     prelude::{Alignment, Color, Frame, Rect, Span},
     style::{Style, Stylize},
     widgets::{block::Position, Block, Paragraph, Wrap},
 };
 
-use crate::wordle::model::{GameResult, LetterState, Model, RunningState};
+use std::time::Instant;
 
+use crate::wordle;
+use crate::wordle::daily;
+use crate::wordle::model::{GameMode, GameResult, LetterState, Message, Model, RunningState};
+use crate::wordle::settings::Theme;
+use crate::wordle::timer;
+
+mod analysis;
 mod grid;
+mod hints;
 mod keyboard;
 mod layout;
+mod speedrun;
+mod stats;
+mod toast;
 
 // [ELM VIEW] view is a function of model
 pub fn view(model: &mut Model, f: &mut Frame) {
+    let master_layout = layout::master_layout(f.size(), grid::height(model));
+    let keys = model.settings.key_bindings;
+
     let block = Block::default()
         // .title(format!("{}", model.wordle,))
         .title_alignment(Alignment::Center)
@@ -20,11 +35,10 @@ pub fn view(model: &mut Model, f: &mut Frame) {
         .title(get_status(model))
         .title_position(Position::Top);
 
-    let master_layout = layout::master_layout(f);
-
-    let common_text =
-        "Type and enter the guess. Backspace to clear. Ctrl-N for new wordle. Esc/Ctrl-C to quit."
-            .to_string();
+    let common_text = format!(
+        "Type and enter the guess. Backspace to clear. {} for new wordle. {} for stats. {} for hints. {}/Ctrl-C to quit.",
+        keys.new_game, keys.stats, keys.hints, keys.quit
+    );
     let help_text = if master_layout.len() == 1 {
         format!(
             "{}. Check https://github.com/palerdot/wordl-rs for more info.",
@@ -57,40 +71,169 @@ pub fn view(model: &mut Model, f: &mut Frame) {
 
     if has_min_height {
         // keyboard layout
-        keyboard::draw(f, master_layout[1], &mut model.keyboard_hints);
+        keyboard::draw(f, master_layout[1], model);
+    }
+
+    if model.speedrun.is_some() {
+        speedrun::draw(f, master_layout[0], model);
+    }
+
+    if model.show_hints {
+        hints::draw(f, master_layout[0], model);
+    }
+
+    if let Some(text) = &model.toast {
+        toast::draw(f, master_layout[0], text);
+    }
+
+    // stats modal is drawn over everything else
+    if model.show_stats {
+        stats::draw(f, f.size(), model);
+    }
+
+    if model.show_analysis {
+        analysis::draw(f, f.size(), model);
+    }
+}
+
+// clicks on the keyboard keys and on the letters of the active guess; same layout as `view`
+pub fn handle_mouse_event(model: &Model, size: Rect, mouse_event: MouseEvent) -> Option<Message> {
+    if mouse_event.kind != MouseEventKind::Down(MouseButton::Left) {
+        return None;
     }
+    // modals cover the game
+    if model.show_stats || model.show_analysis {
+        return None;
+    }
+
+    let master_layout = layout::master_layout(size, grid::height(model));
+    let (column, row) = (mouse_event.column, mouse_event.row);
+
+    if let Some(keyboard) = master_layout.get(1) {
+        if let Some(key) = keyboard::key_at(*keyboard, model.settings.keyboard_layout, column, row)
+        {
+            return Some(key.message());
+        }
+    }
+
+    grid::cell_at(master_layout[0], model, column, row).map(Message::Focus)
 }
 
-fn get_status(model: &mut Model) -> Span {
-    let step = model.guesses.len();
+fn get_status(model: &mut Model) -> Span<'_> {
+    let step = model.attempts();
+    let now = Instant::now();
+    // game clock; speedrun shows the total time and beat the clock the time left
+    let mut prefix = match (
+        &model.speedrun,
+        model.speedrun_time(now),
+        model.time_left(now),
+    ) {
+        (Some(speedrun), Some(total), _) => {
+            let is_over = matches!(model.running_state, RunningState::Over(_));
+            let current = speedrun.splits.len() + usize::from(!is_over);
+            format!(
+                "Speedrun {}/{} · ⏱ {} · ",
+                current.min(speedrun.wordles),
+                speedrun.wordles,
+                timer::format_clock(total)
+            )
+        }
+        (_, _, Some(time_left)) => format!("⏳ {} · ", timer::format_clock(time_left)),
+        _ => format!("⏱ {} · ", timer::format_clock(model.clock.elapsed(now))),
+    };
+    // daily puzzles are prefixed with the puzzle number
+    if let Some(number) = model.puzzle_number {
+        prefix.push_str(&format!("Daily #{} · ", number));
+    }
+    if let Some(name) = wordle::game::board_mode_name(model.boards.len()) {
+        prefix.push_str(&format!("{} · ", name));
+    }
+    if model.hard_mode {
+        prefix.push_str("Hard · ");
+    }
+    // survival shows the words solved in the run, and the longest run so far
+    if let Some(survival) = &model.survival {
+        let best = model
+            .stats
+            .best_survival(
+                survival.start_budget,
+                model.boards.len(),
+                model.word_length(),
+                model.hard_mode,
+            )
+            .max(survival.run_length());
+        prefix.push_str(&format!(
+            "Survival · Run {} · Best {} · ",
+            survival.run_length(),
+            best
+        ));
+    }
+    // absurdle keeps the number of words left visible; not shown while the guess is revealed
+    if model.mode == GameMode::Absurdle {
+        prefix.push_str("Absurdle · ");
+        if step > 0 && model.running_state != RunningState::Calculating {
+            prefix.push_str(&format!("{} words left · ", model.candidates.len()));
+        }
+    }
 
     match &model.running_state {
         RunningState::Waiting => Span::styled(
-            format!("{}/6: Enter your guess", step),
+            format!(
+                "{}{}/{}: Enter your guess",
+                prefix, step, model.max_attempts
+            ),
             Style::default()
                 .fg(Color::Rgb(189, 189, 189))
                 .bg(Color::Rgb(0, 0, 0)),
         ),
         RunningState::Calculating => Span::styled(
-            format!("{}/6: Checking", step),
+            format!("{}{}/{}: Checking", prefix, step, model.max_attempts),
             Style::default()
                 .fg(Color::Rgb(189, 189, 189))
                 .bg(Color::Rgb(0, 0, 0)),
         ),
         RunningState::Over(result) => {
-            let is_correct = if *result == GameResult::CorrectGuess {
-                true
+            let is_correct = *result == GameResult::CorrectGuess;
+
+            // wordles of the boards that were not solved
+            let answers: Vec<String> = model
+                .boards
+                .iter()
+                .filter(|board| !board.is_solved())
+                .map(|board| board.wordle.to_uppercase())
+                .collect();
+            // countdown to the next daily puzzle
+            let mut suffix = if model.mode == GameMode::Daily {
+                format!(
+                    " · Next wordle in {}",
+                    daily::format_countdown(daily::time_until_next_puzzle())
+                )
             } else {
-                false
+                "".into()
             };
 
-            let answer = model.wordle.to_uppercase().to_string();
+            // eg: copied to clipboard
+            if let Some(message) = &model.message {
+                suffix.push_str(&format!(" · {}", message));
+            }
 
             Span::styled(
                 if is_correct {
-                    "Correct 😇".into()
+                    format!("{}Correct 😇{}", prefix, suffix)
                 } else {
-                    format!("{} is the correct word", answer)
+                    match answers.as_slice() {
+                        // absurdle wordle is one of the words left
+                        [answer] if model.mode == GameMode::Absurdle => {
+                            format!("{}{} was one of them{}", prefix, answer, suffix)
+                        }
+                        [answer] => format!("{}{} is the correct word{}", prefix, answer, suffix),
+                        _ => format!(
+                            "{}{} are the correct words{}",
+                            prefix,
+                            answers.join(", "),
+                            suffix
+                        ),
+                    }
                 },
                 Style::default()
                     .fg(if is_correct {
@@ -111,11 +254,13 @@ fn get_status(model: &mut Model) -> Span {
 }
 
 // helper function to get grid color
-pub fn get_grid_color(letter_state: LetterState) -> Color {
-    match letter_state {
-        LetterState::Correct => Color::Rgb(0, 135, 0),
-        LetterState::Incorrect => Color::Rgb(215, 175, 0),
-        LetterState::NotPresent => Color::Rgb(88, 88, 88),
-        LetterState::Unknown => Color::Rgb(48, 48, 48),
+pub fn get_grid_color(theme: Theme, letter_state: LetterState) -> Color {
+    match (theme, letter_state) {
+        (Theme::HighContrast, LetterState::Correct) => Color::Rgb(245, 121, 58),
+        (Theme::HighContrast, LetterState::Incorrect) => Color::Rgb(133, 192, 249),
+        (_, LetterState::Correct) => Color::Rgb(0, 135, 0),
+        (_, LetterState::Incorrect) => Color::Rgb(215, 175, 0),
+        (_, LetterState::NotPresent) => Color::Rgb(88, 88, 88),
+        (_, LetterState::Unknown) => Color::Rgb(48, 48, 48),
     }
 }
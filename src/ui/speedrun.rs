@@ -0,0 +1,103 @@
+use ratatui::widgets::block::Title;
+use ratatui::{prelude::*, widgets::*};
+use std::time::Instant;
+
+use crate::wordle::model::Model;
+use crate::wordle::timer;
+
+// screen width needed to fit the panel next to the grid
+const MIN_WIDTH: u16 = 90;
+
+// speedrun splits panel drawn on the left side of the grid
+pub fn draw(frame: &mut Frame, rect: Rect, model: &mut Model) {
+    let Some(speedrun) = &model.speedrun else {
+        return;
+    };
+    // panel would cover the grid on narrow screens; status still shows the run progress
+    if rect.width < MIN_WIDTH {
+        return;
+    }
+
+    let width = 30.min(rect.width);
+    // one row per wordle, plus total and best times
+    let height = (speedrun.wordles as u16 + 7).min(rect.height.saturating_sub(2));
+    let area = Rect {
+        x: rect.left(),
+        y: rect.top() + 1,
+        width,
+        height,
+    };
+
+    let text_style = Style::new().fg(Color::Rgb(255, 255, 255));
+    let label_style = Style::new().fg(Color::Rgb(189, 189, 189));
+    let won_style = Style::new().fg(Color::Rgb(0, 255, 0));
+    let lost_style = Style::new().fg(Color::Rgb(255, 95, 135));
+
+    let mut lines = vec![Line::from("")];
+
+    for (index, split) in speedrun.splits.iter().enumerate() {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:>3}. ", index + 1), label_style),
+            Span::styled(
+                format!("{:<8}", split.wordle.to_uppercase()),
+                text_style.bold(),
+            ),
+            Span::styled(
+                format!("{:>3} ", split.attempts),
+                if split.won { won_style } else { lost_style },
+            ),
+            Span::styled(timer::format_split(split.time), label_style),
+        ]));
+    }
+    // wordles yet to be played
+    for index in speedrun.splits.len()..speedrun.wordles {
+        lines.push(Line::from(Span::styled(
+            format!("{:>3}. {:<8}{:>4}{:>7}", index + 1, "-----", "", "--:--.-"),
+            label_style,
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Total ", label_style),
+        Span::styled(
+            timer::format_split(
+                model
+                    .speedrun_time(Instant::now())
+                    .unwrap_or(speedrun.total_time()),
+            ),
+            text_style.bold(),
+        ),
+    ]));
+    let best = model.stats.best_speedrun(
+        speedrun.wordles,
+        model.max_attempts,
+        model.boards.len(),
+        model.word_length(),
+        model.hard_mode,
+    );
+    if let Some(best_ms) = best {
+        lines.push(Line::from(vec![
+            Span::styled("Best ", label_style),
+            Span::styled(
+                timer::format_split(std::time::Duration::from_millis(best_ms)),
+                text_style,
+            ),
+        ]));
+    }
+
+    let block = Block::new()
+        .title(Title::from(" SPEEDRUN ").alignment(Alignment::Center))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(Color::Cyan))
+        .style(Style::new().bg(Color::Rgb(0, 0, 0)));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Center),
+        area,
+    );
+}
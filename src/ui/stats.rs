@@ -0,0 +1,120 @@
+use ratatui::widgets::block::Title;
+use ratatui::{prelude::*, widgets::*};
+
+use super::get_grid_color;
+use crate::wordle::model::{GameResult, LetterState, Model, RunningState};
+
+// stats modal drawn on top of the game
+pub fn draw(frame: &mut Frame, rect: Rect, model: &mut Model) {
+    let width = 44.min(rect.width);
+    // one histogram row per allowed attempt
+    let height = (11 + model.max_attempts as u16).min(rect.height);
+    let area = Rect {
+        x: rect.left() + (rect.width - width) / 2,
+        y: rect.top() + (rect.height - height) / 2,
+        width,
+        height,
+    };
+
+    let stats = &model.stats;
+    // games with the same number of boards and word length as the current one
+    let boards = model.boards.len();
+    let word_length = model.word_length();
+    // bar of the current game is highlighted
+    let current_attempts = match model.running_state {
+        RunningState::Over(GameResult::CorrectGuess) => Some(model.attempts()),
+        _ => None,
+    };
+
+    let text_style = Style::new().fg(Color::Rgb(255, 255, 255));
+    let label_style = Style::new().fg(Color::Rgb(189, 189, 189));
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                format!("{:^10}", stats.played(boards, word_length)),
+                text_style.bold(),
+            ),
+            Span::styled(
+                format!("{:^10}", stats.win_percentage(boards, word_length)),
+                text_style.bold(),
+            ),
+            Span::styled(
+                format!("{:^10}", stats.current_streak(boards, word_length)),
+                text_style.bold(),
+            ),
+            Span::styled(
+                format!("{:^10}", stats.max_streak(boards, word_length)),
+                text_style.bold(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(format!("{:^10}", "Played"), label_style),
+            Span::styled(format!("{:^10}", "Win %"), label_style),
+            Span::styled(format!("{:^10}", "Streak"), label_style),
+            Span::styled(format!("{:^10}", "Max Streak"), label_style),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("GUESS DISTRIBUTION", text_style.bold())),
+        Line::from(""),
+    ];
+
+    // histogram; bars are scaled relative to the most frequent attempt count
+    let distribution = stats.guess_distribution(model.max_attempts, boards, word_length);
+    let max_count = distribution.iter().max().copied().unwrap_or(0).max(1);
+    let max_bar_width = usize::from(width).saturating_sub(8);
+
+    for (index, count) in distribution.iter().enumerate() {
+        let attempts = index + 1;
+        let bar_width = (count * max_bar_width / max_count).max(count.to_string().len() + 2);
+        let bar_color = get_grid_color(
+            model.settings.theme,
+            if current_attempts == Some(attempts) {
+                LetterState::Correct
+            } else {
+                LetterState::NotPresent
+            },
+        );
+
+        lines.push(
+            Line::from(vec![
+                Span::styled(format!("{:>3} ", attempts), text_style),
+                Span::styled(
+                    format!("{:>width$} ", count, width = bar_width - 1),
+                    text_style.bg(bar_color).bold(),
+                ),
+            ])
+            .alignment(Alignment::Left),
+        );
+    }
+
+    let keys = model.settings.key_bindings;
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("{} to share. {} to close.", keys.share, keys.stats),
+        label_style,
+    )));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "{} for analysis. {} for new wordle.",
+            keys.analysis, keys.new_game
+        ),
+        label_style,
+    )));
+
+    let block = Block::new()
+        .title(Title::from(" STATISTICS ").alignment(Alignment::Center))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(Color::Cyan))
+        .style(Style::new().bg(Color::Rgb(0, 0, 0)));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Center),
+        area,
+    );
+}
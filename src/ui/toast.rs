@@ -0,0 +1,33 @@
+use ratatui::{prelude::*, widgets::*};
+
+// transient message drawn over the top of the grid (eg: rejected guess)
+pub fn draw(frame: &mut Frame, rect: Rect, text: &str) {
+    let width = (text.chars().count() as u16 + 4).min(rect.width);
+    let height = 3.min(rect.height);
+    let area = Rect {
+        x: rect.left() + (rect.width - width) / 2,
+        y: rect.top() + 1,
+        width,
+        height,
+    };
+
+    let block = Block::new()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(Color::Rgb(255, 255, 255)))
+        .style(Style::new().bg(Color::Rgb(255, 255, 255)));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(text.to_string())
+            .block(block)
+            .style(
+                Style::new()
+                    .fg(Color::Rgb(0, 0, 0))
+                    .bg(Color::Rgb(255, 255, 255))
+                    .bold(),
+            )
+            .alignment(Alignment::Center),
+        area,
+    );
+}
@@ -1,10 +1,30 @@
+use chrono::Local;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::{Duration, Instant};
 use wordle::model::{Message, Model, RunningState};
 
 use crate::events::EventHandler;
+use crate::save::SavedGame;
+use crate::share;
+use crate::stats::{GameRecord, SpeedrunRecord, SplitRecord, SurvivalRecord};
 use crate::wordle;
-use crate::wordle::model::GameResult;
-// use crate::wordle::model::{LetterState, LetterStatus};
+use crate::wordle::analysis;
+use crate::wordle::model::{BoardGuess, GameMode, GameResult};
+use crate::wordle::settings::{KeyBinding, KeyBindings, KeyName};
+use crate::wordle::solver;
+
+// number of next guess suggestions shown in hints
+const HINTS_COUNT: usize = 5;
+// rejected guess: time between the frames of the shake, and how long the toast is shown; the
+// toast stays long enough to be read even with instant animations
+const SHAKE_FRAME_MS: u64 = 40;
+const TOAST_MS: u64 = 1500;
+const MIN_TOAST_MS: u64 = 1000;
+// game is over: pause to look at the final guess before the next survival word, the next
+// speedrun wordle or the stats
+const NEXT_WORD_MS: u64 = 1000;
+const NEXT_WORDLE_MS: u64 = 1500;
+const SHOW_STATS_MS: u64 = 1000;
 
 pub async fn update(model: &mut Model, msg: Message, event_handler: &EventHandler) {
     match msg {
@@ -14,86 +34,227 @@ pub async fn update(model: &mut Model, msg: Message, event_handler: &EventHandle
                 return;
             }
 
-            // we have to handle the letter only if active guess is incomplete
-            if model.active_guess.len() < 5 {
-                let updated_guess = format!("{}{}", model.active_guess, letter.to_lowercase())
-                    .to_lowercase()
-                    .into();
-                model.active_guess = updated_guess;
+            // user is typing again; clear previous message
+            model.message = None;
+            // clock starts with the first letter
+            model.clock.start(Instant::now());
+
+            if let Some(column) = model.type_letter(letter) {
+                model.pop_letter(column, Instant::now());
+            }
+        }
+        Message::SkipAnimations => {
+            model.animations.clear();
+
+            // rest of the guess is revealed right away
+            if let Some(board_guesses) = model.reveal.take() {
+                end_guess(model, board_guesses, event_handler).await;
+            }
+        }
+        Message::Focus(position) => {
+            if model.running_state == RunningState::Waiting {
+                model.focus(position);
             }
         }
         Message::CalculateStart => {
-            // start calculation only if the guess has 5 letters
-            if model.active_guess.len() != 5 {
+            // start calculation only if waiting for a guess, and the guess has all the letters
+            if model.running_state != RunningState::Waiting
+                || model.active_guess.len() != model.word_length()
+            {
                 return;
             }
 
             // we have to check if active guess is valid
-            let is_valid_active_guess = model.valid_wordles.contains(&model.active_guess)
-                || model.valid_guesses.contains(&model.active_guess);
+            if let Err(error) = wordle::utils::validate_guess(
+                &model.active_guess,
+                model.word_length(),
+                &model.dictionary,
+            ) {
+                // guess is kept, so that user can correct it
+                reject_guess(model, &error.to_string(), event_handler).await;
 
-            if !is_valid_active_guess {
-                // reset active guess and abort
-                model.active_guess = "".into();
+                return;
+            }
+
+            // in hard mode, revealed hints have to be used
+            if let Err(error) = model.check_hard_mode(&model.active_guess) {
+                reject_guess(model, &error.to_string(), event_handler).await;
+
+                return;
+            }
+
+            // absurdle picks the wordle only now; every other mode has it already
+            let guess = model.active_guess.to_string();
+            if let Err(error) = model.narrow_candidates(&guess) {
+                reject_guess(model, &error.to_string(), event_handler).await;
 
                 return;
             }
 
+            let board_guesses = match model.check_guess(&guess) {
+                Ok(board_guesses) => board_guesses,
+                Err(error) => {
+                    reject_guess(model, &error.to_string(), event_handler).await;
+
+                    return;
+                }
+            };
+
             // first change state to calculating
             model.running_state = RunningState::Calculating;
-            let guess =
-                wordle::utils::check(model.wordle.to_string(), model.active_guess.to_string());
+            // guess is added to every unsolved board; letter statuses are shown as the tiles flip
+            let now = Instant::now();
+            for BoardGuess { board, guess } in &board_guesses {
+                let row = model.boards[*board].guesses.len();
+                model.boards[*board].guesses.push(guess.clone());
+                model.animations.flip_row(*board, row, guess.len(), now);
+            }
             // reset active guess
             model.active_guess = "".into();
-            let latest_position = model.guesses.len();
-            // // insert empty vector
-            model.guesses.insert(latest_position, Vec::new());
+            model.cursor = None;
 
-            event_handler
-                .send_delayed_message(15, Message::AnimateGuess(0, guess))
-                .await;
+            // guess is over once the last tile is flipped; checked on every tick
+            // without animations, right away
+            if model.animations.is_blocking(now) {
+                model.reveal = Some(board_guesses);
+            } else {
+                end_guess(model, board_guesses, event_handler).await;
+            }
         }
+        Message::Shake(toast, frame) => {
+            // a newer rejection restarts the shake
+            if toast != model.toast_count {
+                return;
+            }
 
-        Message::AnimateGuess(guess_position, guess) => {
-            let latest_position = model.guesses.len() - 1;
-
-            if let Some(current) = model.guesses.get_mut(latest_position) {
-                if let Some(guess_letter) = guess.get(guess_position) {
-                    current.push(guess_letter.clone());
-                    // once last letter is animated don't send delayed event
-                    let is_last_letter = guess_position + 1 == guess.len();
-
-                    if is_last_letter {
-                        event_handler
-                            .send_delayed_message(0, Message::CalculateEnd(guess))
-                            .await;
-                    } else {
-                        event_handler
-                            .send_delayed_message(
-                                515,
-                                Message::AnimateGuess(guess_position + 1, guess),
-                            )
-                            .await;
-                    }
+            if frame < wordle::model::SHAKE_OFFSETS.len() {
+                model.shake_frame = Some(frame);
+                let delay = model.animations.settings.duration(SHAKE_FRAME_MS);
+                event_handler
+                    .send_delayed_message(as_millis(delay), Message::Shake(toast, frame + 1))
+                    .await;
+            } else {
+                model.shake_frame = None;
+            }
+        }
+        Message::HideToast(toast) => {
+            if toast == model.toast_count {
+                model.toast = None;
+            }
+        }
+
+        Message::Tick => {
+            let now = Instant::now();
+            model.animations.prune(now);
+
+            // revealed guess is checked once the tiles are flipped
+            if !model.animations.is_blocking(now) {
+                if let Some(board_guesses) = model.reveal.take() {
+                    end_guess(model, board_guesses, event_handler).await;
                 }
             }
+
+            // beat the clock: game is lost once the countdown hits zero; guess being revealed is
+            // checked once it is done
+            if model.running_state == RunningState::Waiting && model.is_out_of_time(Instant::now())
+            {
+                model.active_guess = "".into();
+                model.cursor = None;
+                finish_game(model, GameResult::WrongGuess, event_handler).await;
+            }
+        }
+        Message::NextWordle => {
+            let is_next = matches!(model.running_state, RunningState::Over(_))
+                && model
+                    .speedrun
+                    .as_ref()
+                    .is_some_and(|speedrun| !speedrun.is_finished());
+            if is_next {
+                model.reset();
+                // clock of the next wordle is running right away
+                model.clock.start(Instant::now());
+            }
+        }
+
+        Message::ShowStats => {
+            // sent a moment after the game is over; the game may have been reset since
+            model.open_stats();
+        }
+        Message::ToggleStats => {
+            model.show_stats = !model.show_stats;
         }
-        Message::CalculateEnd(guess) => {
-            // update keyboard hint
-            wordle::utils::update_keyboard_hints(&mut model.keyboard_hints, guess.clone());
+        Message::ToggleHints => {
+            if model.show_hints {
+                model.show_hints = false;
+                return;
+            }
 
-            let is_correct_guess = wordle::utils::is_correct_guess(guess.clone());
-            let is_attempts_over = model.guesses.len() == 6;
-            let is_over = is_correct_guess || is_attempts_over;
+            // hints are useful only while the game is on
+            if model.running_state != RunningState::Waiting {
+                return;
+            }
 
-            if is_over {
-                model.running_state = RunningState::Over(if is_correct_guess {
-                    GameResult::CorrectGuess
-                } else {
-                    GameResult::WrongGuess
-                });
-            } else {
-                model.running_state = RunningState::Waiting;
+            model.show_hints = true;
+            let is_stale = model
+                .hints
+                .as_ref()
+                .is_none_or(|hints| hints.attempt != model.attempts());
+            if is_stale {
+                request_hints(model, event_handler);
+            }
+        }
+        Message::HintsReady(hints) => {
+            if hints.attempt == 0 {
+                model.opening_hints = Some(hints.clone());
+            }
+            // ignore hints computed for an earlier guess
+            if hints.attempt == model.attempts() {
+                model.hints = Some(hints);
+            }
+        }
+        Message::ToggleAnalysis => {
+            if model.show_analysis {
+                model.show_analysis = false;
+                return;
+            }
+
+            // guesses are rated only once the game is over
+            if !matches!(model.running_state, RunningState::Over(_)) {
+                return;
+            }
+
+            // analysis is drawn in place of the stats
+            model.show_stats = false;
+            model.show_analysis = true;
+            let is_stale = model
+                .analysis
+                .as_ref()
+                .is_none_or(|analysis| analysis.attempt != model.attempts());
+            if is_stale {
+                request_analysis(model, event_handler);
+            }
+        }
+        Message::AnalysisReady(analysis) => {
+            // ignore analysis of an earlier game
+            let is_over = matches!(model.running_state, RunningState::Over(_));
+            if is_over && analysis.attempt == model.attempts() {
+                model.analysis = Some(analysis);
+            }
+        }
+        Message::Share => {
+            // share only if the game is over
+            if let Some(text) = share::share_text(model) {
+                // printed on exit only if the terminal does not support clipboard access
+                match share::copy_to_clipboard(&text) {
+                    Ok(_) => {
+                        model.message = Some("Copied to clipboard".into());
+                        model.share_text = None;
+                    }
+                    Err(_) => {
+                        model.message = Some("Results will be printed on exit".into());
+                        model.share_text = Some(text);
+                    }
+                }
             }
         }
 
@@ -103,36 +264,282 @@ pub async fn update(model: &mut Model, msg: Message, event_handler: &EventHandle
                 return;
             }
 
-            if model.active_guess.len() > 0 {
-                model.active_guess.pop();
-            }
+            model.message = None;
+            model.erase_letter();
         }
         Message::Reset => {
+            // next wordle of a speedrun comes on its own, with its clock running right away
+            let is_running_speedrun = model
+                .speedrun
+                .as_ref()
+                .is_some_and(|speedrun| !speedrun.is_finished());
+            if is_running_speedrun {
+                return;
+            }
+
             // reset only if the game is in over stage
-            match model.running_state {
-                RunningState::Over(_) => {
-                    // we will reset only if the game is over
-                    model.reset();
-                }
-                _ => {}
+            if let RunningState::Over(_) = model.running_state {
+                // we will reset only if the game is over
+                model.reset();
             }
         }
         Message::Quit => {
+            // guess being revealed is checked first, so that a game it ends is recorded in the
+            // stats before it is saved
+            if let Some(board_guesses) = model.reveal.take() {
+                model.animations.clear();
+                end_guess(model, board_guesses, event_handler).await;
+            }
+
+            // unfinished game is saved to be resumed on next launch; saving is best effort
+            // finished daily puzzle is kept as well, so that it is not replayed on the same day
+            // timed games and runs cannot be resumed
+            let is_finished = matches!(model.running_state, RunningState::Over(_));
+            let saved_game = SavedGame::from_model(model);
+            let _ = if !model.is_resumable() {
+                Ok(())
+            } else if is_finished && model.mode != GameMode::Daily {
+                saved_game.clear()
+            } else {
+                saved_game.save()
+            };
+
             model.running_state = RunningState::Done;
         }
     }
 }
 
-pub fn handle_key_event(key_event: KeyEvent) -> Option<Message> {
-    match key_event.code {
-        // https://ratatui.rs/templates/async/config-rs/
-        KeyCode::Char('n') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            Some(Message::Reset)
+// guess is revealed; keyboard hints are updated, and the game is finished if it is over
+async fn end_guess(
+    model: &mut Model,
+    board_guesses: Vec<BoardGuess>,
+    event_handler: &EventHandler,
+) {
+    // update keyboard hints of the boards
+    for BoardGuess { board, guess } in board_guesses {
+        wordle::utils::update_keyboard_hints(&mut model.boards[board].keyboard_hints, guess);
+    }
+
+    // beat the clock: time may have run out while the guess was revealed
+    let result = model.result().or_else(|| {
+        model
+            .is_out_of_time(Instant::now())
+            .then_some(GameResult::WrongGuess)
+    });
+
+    if let Some(result) = result {
+        // winning rows bounce
+        if result == GameResult::CorrectGuess && model.boards.len() == 1 {
+            let row = model.attempts().saturating_sub(1);
+            let columns = model.word_length();
+            model.animations.bounce_row(0, row, columns, Instant::now());
         }
-        KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-            Some(Message::Quit)
+        finish_game(model, result, event_handler).await;
+    } else {
+        model.running_state = RunningState::Waiting;
+
+        // hints are open; refresh them for the next guess
+        if model.show_hints {
+            request_hints(model, event_handler);
+        }
+    }
+}
+
+// rejected guess; toast with the reason is shown over the grid, and the active row shakes
+async fn reject_guess(model: &mut Model, reason: &str, event_handler: &EventHandler) {
+    let toast = model.show_toast(reason);
+
+    // rows do not shake with reduced motion
+    let settings = model.animations.settings;
+    if settings.has_motion() {
+        model.shake_frame = Some(0);
+        event_handler
+            .send_delayed_message(
+                as_millis(settings.duration(SHAKE_FRAME_MS)),
+                Message::Shake(toast, 1),
+            )
+            .await;
+    }
+    event_handler
+        .send_delayed_message(
+            as_millis(settings.duration(TOAST_MS)).max(MIN_TOAST_MS),
+            Message::HideToast(toast),
+        )
+        .await;
+}
+
+// game is over; records the result, and moves on to the next wordle of a speedrun or survival run
+async fn finish_game(model: &mut Model, result: GameResult, event_handler: &EventHandler) {
+    let is_correct_guess = result == GameResult::CorrectGuess;
+    let settings = model.animations.settings;
+    model.finish(result, Instant::now());
+    model.show_hints = false;
+
+    if let Some(survival) = &model.survival {
+        // next word starts right after the final guess is seen
+        if !model.is_run_over() {
+            event_handler
+                .send_delayed_message(as_millis(settings.duration(NEXT_WORD_MS)), Message::Reset)
+                .await;
+            return;
+        }
+
+        let record = SurvivalRecord {
+            words: survival.words.clone(),
+            failed_word: model.wordles().join(","),
+            hard_mode: model.hard_mode,
+            max_attempts: survival.start_budget,
+            boards: model.boards.len(),
+            word_length: model.word_length(),
+            played_at: Local::now().to_rfc3339(),
+        };
+        let _ = model.stats.record_survival(record);
+    }
+
+    // stats are best effort; game can continue even if they cannot be saved
+    // absurdle games are not comparable with the others, so they are not recorded
+    // survival words are played with varying budgets; they are recorded as runs
+    if model.mode != GameMode::Absurdle && model.survival.is_none() {
+        let _ = model.stats.record(GameRecord {
+            wordle: model.wordles().join(","),
+            won: is_correct_guess,
+            attempts: model.attempts(),
+            hard_mode: model.hard_mode,
+            max_attempts: model.max_attempts,
+            boards: model.boards.len(),
+            word_length: model.word_length(),
+            puzzle_number: model.puzzle_number,
+            played_at: Local::now().to_rfc3339(),
+        });
+    }
+
+    if let Some(speedrun) = &model.speedrun {
+        // next wordle once the user had a moment to look at the final guess
+        if !speedrun.is_finished() {
+            event_handler
+                .send_delayed_message(
+                    as_millis(settings.duration(NEXT_WORDLE_MS)),
+                    Message::NextWordle,
+                )
+                .await;
+            return;
         }
-        KeyCode::Esc => Some(Message::Quit),
+
+        let record = SpeedrunRecord {
+            splits: speedrun
+                .splits
+                .iter()
+                .map(|split| SplitRecord {
+                    wordle: split.wordle.to_string(),
+                    won: split.won,
+                    attempts: split.attempts,
+                    time_ms: as_millis(split.time),
+                })
+                .collect(),
+            total_ms: as_millis(speedrun.total_time()),
+            solved: speedrun.solved(),
+            hard_mode: model.hard_mode,
+            max_attempts: model.max_attempts,
+            boards: model.boards.len(),
+            word_length: model.word_length(),
+            played_at: Local::now().to_rfc3339(),
+        };
+        let _ = model.stats.record_speedrun(record);
+    }
+
+    // show stats once the user had a moment to look at the final guess
+    event_handler
+        .send_delayed_message(
+            as_millis(settings.duration(SHOW_STATS_MS)),
+            Message::ShowStats,
+        )
+        .await;
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    duration.as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+// solver runs on a separate thread; hints are sent back as a message once ready
+// in multi board modes, hints are for the first unsolved board
+fn request_hints(model: &mut Model, event_handler: &EventHandler) {
+    model.hints = None;
+
+    let Some(board) = model.boards.iter().find(|board| !board.is_solved()) else {
+        return;
+    };
+    // first guess hints take a while; they are computed once
+    if board.guesses.is_empty() && model.opening_hints.is_some() {
+        model.hints = model.opening_hints.clone();
+        return;
+    }
+    let guesses = board.guesses.clone();
+    let valid_wordles = model.valid_wordles.clone();
+    let valid_guesses = model.valid_guesses.clone();
+
+    event_handler.send_blocking_message(move || {
+        Message::HintsReady(solver::hints(
+            &guesses,
+            &valid_wordles.as_strs(),
+            &valid_guesses.as_strs(),
+            HINTS_COUNT,
+        ))
+    });
+}
+
+// analysis runs on a separate thread, like the hints
+// in multi board modes, guesses of the first board are analysed
+fn request_analysis(model: &mut Model, event_handler: &EventHandler) {
+    model.analysis = None;
+
+    let Some(board) = model.boards.first() else {
+        return;
+    };
+    let guesses = board.guesses.clone();
+    let valid_wordles = model.valid_wordles.clone();
+    let valid_guesses = model.valid_guesses.clone();
+
+    event_handler.send_blocking_message(move || {
+        Message::AnalysisReady(analysis::analyze(
+            &guesses,
+            &valid_wordles.as_strs(),
+            &valid_guesses.as_strs(),
+        ))
+    });
+}
+
+pub fn handle_key_event(model: &Model, key_event: KeyEvent) -> Option<Message> {
+    let message = key_message(&model.settings.key_bindings, key_event);
+
+    // any key skips the reveal and the win animations; except quitting
+    let is_animating = model.reveal.is_some() || model.animations.is_blocking(Instant::now());
+    if is_animating && message.as_ref().is_some_and(|x| *x != Message::Quit) {
+        return Some(Message::SkipAnimations);
+    }
+
+    message
+}
+
+fn key_message(key_bindings: &KeyBindings, key_event: KeyEvent) -> Option<Message> {
+    let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+    // ctrl-c always quits
+    if ctrl && key_event.code == KeyCode::Char('c') {
+        return Some(Message::Quit);
+    }
+
+    // https://ratatui.rs/templates/async/config-rs/
+    let key = match key_event.code {
+        KeyCode::Char(letter) => Some(KeyName::Char(letter.to_ascii_lowercase())),
+        KeyCode::Esc => Some(KeyName::Esc),
+        KeyCode::Tab => Some(KeyName::Tab),
+        KeyCode::F(number) => Some(KeyName::F(number)),
+        _ => None,
+    };
+    if let Some(message) = key.and_then(|key| key_bindings.message(KeyBinding { key, ctrl })) {
+        return Some(message);
+    }
+
+    match key_event.code {
         KeyCode::Char(letter) => {
             if letter.is_ascii_alphabetic() {
                 Some(Message::Listen(letter))
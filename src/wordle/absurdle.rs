@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use super::error::WordlError;
+use super::model::{LetterState, LetterStatus};
+use super::utils;
+
+// Absurdle: wordle is not picked up front. Every guess splits the remaining candidates by the
+// feedback they would give, and the largest group is kept. Feedback is the one shared by that group
+
+// larger than the number of letters in any word, so that a correct letter outweighs any number of
+// present letters
+const CORRECT_LETTER_WEIGHT: usize = utils::MAX_WORD_LENGTH + 1;
+
+// candidates left after the guess; ties are broken in favour of the feedback with fewer correct
+// letters, then fewer present letters, then the group seen first
+pub fn remaining_candidates(candidates: &[String], guess: &str) -> Result<Vec<String>, WordlError> {
+    // groups in the order they are first seen; keeps the choice deterministic
+    let mut buckets: Vec<(Vec<LetterStatus>, Vec<String>)> = Vec::new();
+    let mut bucket_indices: HashMap<Vec<LetterStatus>, usize> = HashMap::new();
+
+    for candidate in candidates {
+        let feedback = utils::check(candidate.to_string(), guess.to_string())?;
+
+        match bucket_indices.get(&feedback) {
+            Some(&index) => buckets[index].1.push(candidate.to_string()),
+            None => {
+                bucket_indices.insert(feedback.clone(), buckets.len());
+                buckets.push((feedback, vec![candidate.to_string()]));
+            }
+        }
+    }
+
+    let mut best: Option<(Vec<LetterStatus>, Vec<String>)> = None;
+    for (feedback, words) in buckets {
+        let is_better = best.as_ref().is_none_or(|(best_feedback, best_words)| {
+            (words.len(), hint_score(best_feedback)) > (best_words.len(), hint_score(&feedback))
+        });
+        if is_better {
+            best = Some((feedback, words));
+        }
+    }
+
+    Ok(best.map(|(_, words)| words).unwrap_or_default())
+}
+
+// how much the feedback gives away; correct letters count more than present letters
+fn hint_score(feedback: &[LetterStatus]) -> usize {
+    feedback
+        .iter()
+        .map(|x| match x.status {
+            LetterState::Correct => CORRECT_LETTER_WEIGHT,
+            LetterState::Incorrect => 1,
+            LetterState::NotPresent | LetterState::Unknown => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::absurdle::*;
+
+    fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_remaining_candidates() {
+        // no hints for any candidate; nothing is ruled out
+        let candidates = words(&["pious", "crank", "moist", "craft"]);
+        assert_eq!(
+            remaining_candidates(&candidates, "xylyl").unwrap(),
+            candidates
+        );
+
+        // pious and moist get different hints; crank and craft get none
+        assert_eq!(
+            remaining_candidates(&candidates, "smile").unwrap(),
+            words(&["crank", "craft"])
+        );
+    }
+
+    #[test]
+    fn test_remaining_candidates_ties() {
+        // same group sizes; group without any hint is kept
+        let candidates = words(&["pious", "crane"]);
+        assert_eq!(
+            remaining_candidates(&candidates, "pilot").unwrap(),
+            words(&["crane"])
+        );
+
+        // correct letter gives away more than present letters
+        let candidates = words(&["abbey", "cabal"]);
+        let remaining = remaining_candidates(&candidates, "about").unwrap();
+        assert_eq!(remaining, words(&["cabal"]));
+    }
+
+    #[test]
+    fn test_remaining_candidates_errors() {
+        let candidates = words(&["pious"]);
+        assert_eq!(
+            remaining_candidates(&candidates, "pi"),
+            Err(WordlError::WrongLength {
+                expected: 5,
+                actual: 2
+            })
+        );
+        assert_eq!(remaining_candidates(&[], "pious"), Ok(vec![]));
+    }
+}
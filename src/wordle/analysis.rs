@@ -0,0 +1,247 @@
+use super::model::LetterStatus;
+use super::pattern;
+use super::solver::{self, Suggestion};
+
+/// Breakdown of a guess made during the game
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuessAnalysis {
+    pub word: String,
+    /// wordles that were still possible before the guess
+    pub candidates_before: usize,
+    /// wordles that are still possible after the feedback of the guess
+    pub candidates_after: usize,
+    /// expected information (in bits) of the guess
+    pub entropy: f64,
+    /// information (in bits) the feedback actually gave
+    pub information: f64,
+    /// guess with the highest expected information at that point
+    pub best: Suggestion,
+    /// 0 to 100; expected information of the guess relative to the best guess
+    pub skill: u8,
+    /// 0 to 100; share of the possible wordles that would have left more words than the actual
+    /// wordle did (ties count half). 50 is an average outcome
+    pub luck: u8,
+}
+
+/// Post game analysis of every guess; in the spirit of the WordleBot
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Analysis {
+    /// number of guesses analysed
+    pub attempt: usize,
+    pub guesses: Vec<GuessAnalysis>,
+}
+
+impl Analysis {
+    /// average skill of the guesses
+    pub fn skill(&self) -> u8 {
+        average(self.guesses.iter().map(|x| x.skill))
+    }
+
+    /// average luck of the guesses
+    pub fn luck(&self) -> u8 {
+        average(self.guesses.iter().map(|x| x.luck))
+    }
+}
+
+/// Rates every guess of the feedback history against the best guess the solver would have made
+///
+/// ```
+/// use wordl::{analysis, Game};
+///
+/// let mut game = Game::new("ulcer");
+/// game.guess("crane").unwrap();
+/// game.guess("ulcer").unwrap();
+///
+/// let wordles = vec!["ulcer".to_string(), "crane".to_string(), "pious".to_string()];
+/// let analysis = analysis::analyze(game.guesses(), &wordles, &[]);
+///
+/// assert_eq!(analysis.guesses.len(), 2);
+/// assert_eq!(analysis.guesses[0].candidates_before, 3);
+/// assert_eq!(analysis.guesses[1].candidates_after, 1);
+/// ```
+pub fn analyze<W: AsRef<str>>(
+    guesses: &[Vec<LetterStatus>],
+    valid_wordles: &[W],
+    valid_guesses: &[W],
+) -> Analysis {
+    let all_guesses: Vec<String> = valid_wordles
+        .iter()
+        .chain(valid_guesses.iter())
+        .map(|x| x.as_ref().to_string())
+        .collect();
+
+    let mut analysed = vec![];
+    let mut candidates: Vec<String> = valid_wordles
+        .iter()
+        .map(|x| x.as_ref().to_string())
+        .collect();
+
+    for guess in guesses {
+        let word: String = guess.iter().map(|x| x.letter).collect();
+        let remaining = solver::candidates(std::slice::from_ref(guess), &candidates);
+
+        let best = solver::rank_guesses(&candidates, &all_guesses)
+            .into_iter()
+            .next()
+            .unwrap_or(Suggestion {
+                word: word.to_string(),
+                entropy: 0.0,
+                is_candidate: false,
+            });
+        let entropy = solver::entropy(&word, &candidates);
+
+        analysed.push(GuessAnalysis {
+            candidates_before: candidates.len(),
+            candidates_after: remaining.len(),
+            information: information(candidates.len(), remaining.len()),
+            skill: skill(entropy, &best, candidates.contains(&word)),
+            luck: luck(&word, &candidates, remaining.len()),
+            entropy,
+            best,
+            word,
+        });
+
+        candidates = remaining;
+    }
+
+    Analysis {
+        attempt: guesses.len(),
+        guesses: analysed,
+    }
+}
+
+// bits gained by going from `before` to `after` candidates
+fn information(before: usize, after: usize) -> f64 {
+    if before == 0 || after == 0 {
+        return 0.0;
+    }
+
+    (before as f64 / after as f64).log2()
+}
+
+fn skill(entropy: f64, best: &Suggestion, is_candidate: bool) -> u8 {
+    // only one word was left; guessing it is the only good move
+    if best.entropy <= 0.0 {
+        return if is_candidate { 100 } else { 0 };
+    }
+
+    percentage((entropy / best.entropy).min(1.0))
+}
+
+fn luck(guess: &str, candidates: &[String], remaining: usize) -> u8 {
+    // size of the feedback group every candidate would have ended up in
+    let mut buckets = vec![0usize; pattern::pattern_count(guess.len())];
+    let patterns: Vec<usize> = candidates
+        .iter()
+        .filter_map(|candidate| pattern::score(candidate.as_bytes(), guess.as_bytes()))
+        .map(usize::from)
+        .collect();
+    for pattern in &patterns {
+        buckets[*pattern] += 1;
+    }
+
+    if patterns.is_empty() {
+        return 50;
+    }
+
+    let score: f64 = patterns
+        .iter()
+        .map(|pattern| match buckets[*pattern].cmp(&remaining) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Less => 0.0,
+        })
+        .sum();
+
+    percentage(score / patterns.len() as f64)
+}
+
+fn percentage(ratio: f64) -> u8 {
+    (ratio * 100.0).round().clamp(0.0, 100.0) as u8
+}
+
+fn average(values: impl Iterator<Item = u8>) -> u8 {
+    let values: Vec<u8> = values.collect();
+    if values.is_empty() {
+        return 0;
+    }
+
+    let total: usize = values.iter().map(|x| usize::from(*x)).sum();
+    percentage(total as f64 / values.len() as f64 / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::analysis::*;
+    use crate::wordle::utils::check;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_analyze() {
+        let wordles = words(&["pious", "pilot", "plumb", "crane", "crank"]);
+        let guesses = words(&["zzzzz"]);
+        let feedback: Vec<_> = ["pilot", "pious"]
+            .iter()
+            .map(|guess| check("pious".into(), guess.to_string()).unwrap())
+            .collect();
+
+        let analysis = analyze(&feedback, &wordles, &guesses);
+        assert_eq!(analysis.attempt, 2);
+
+        let first = &analysis.guesses[0];
+        assert_eq!(first.word, "pilot");
+        assert_eq!(first.candidates_before, 5);
+        // pious is the only wordle with 'pi' and no 'l' or 't'
+        assert_eq!(first.candidates_after, 1);
+        assert!((first.information - 5f64.log2()).abs() < 1e-9);
+        // crane and crank share the feedback of 'pilot'; the other wordles are split off
+        let entropy = 3.0 / 5.0 * 5f64.log2() + 2.0 / 5.0 * 2.5f64.log2();
+        assert!((first.entropy - entropy).abs() < 1e-9);
+        assert_eq!(first.best.word, "pilot");
+        assert!((first.best.entropy - entropy).abs() < 1e-9);
+        assert_eq!(first.skill, 100);
+        // crane and crank would have left more words; the other 3 wordles as many
+        assert_eq!(first.luck, 70);
+
+        let last = &analysis.guesses[1];
+        assert_eq!(last.candidates_before, 1);
+        assert_eq!(last.candidates_after, 1);
+        assert_eq!(last.best.word, "pious");
+        assert_eq!(last.skill, 100);
+        assert_eq!(last.luck, 50);
+    }
+
+    #[test]
+    fn test_skill_and_luck() {
+        let best = Suggestion {
+            word: "crane".into(),
+            entropy: 2.0,
+            is_candidate: true,
+        };
+        assert_eq!(skill(2.0, &best, true), 100);
+        assert_eq!(skill(1.0, &best, false), 50);
+
+        // one word left, and it was not guessed
+        let only = Suggestion {
+            entropy: 0.0,
+            ..best
+        };
+        assert_eq!(skill(0.0, &only, false), 0);
+
+        // 'zzzzz' leaves every wordle; no luck involved
+        let candidates = words(&["pious", "pilot", "crane"]);
+        assert_eq!(luck("zzzzz", &candidates, 3), 50);
+        // crane splits off; pious and pilot share the feedback of 'crane'
+        assert_eq!(luck("crane", &candidates, 1), 83);
+        assert_eq!(luck("crane", &candidates, 2), 33);
+
+        let analysis = Analysis {
+            attempt: 0,
+            guesses: vec![],
+        };
+        assert_eq!(analysis.skill(), 0);
+    }
+}
@@ -0,0 +1,266 @@
+use std::time::{Duration, Instant};
+
+// tile flip revealing a letter; flips of a row overlap
+pub const FLIP_MS: u64 = 500;
+pub const FLIP_STAGGER_MS: u64 = 300;
+// typed letter
+pub const POP_MS: u64 = 120;
+// winning row; tiles bounce one after the other
+pub const BOUNCE_MS: u64 = 400;
+pub const BOUNCE_STAGGER_MS: u64 = 100;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationSpeed {
+    // no animations; guesses are revealed right away
+    Instant,
+    Fast,
+    #[default]
+    Normal,
+    Slow,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnimationSettings {
+    pub speed: AnimationSpeed,
+    // nothing moves; rows are revealed at once, and typed letters, winning rows and rejected
+    // guesses are not animated
+    pub reduced_motion: bool,
+}
+
+impl AnimationSettings {
+    // duration of a normal speed animation at the chosen speed
+    pub fn duration(&self, millis: u64) -> Duration {
+        match self.speed {
+            AnimationSpeed::Instant => Duration::ZERO,
+            AnimationSpeed::Fast => Duration::from_millis(millis / 2),
+            AnimationSpeed::Normal => Duration::from_millis(millis),
+            AnimationSpeed::Slow => Duration::from_millis(millis * 2),
+        }
+    }
+
+    // anything is animated at all; frames need to be drawn often only then
+    pub fn has_motion(&self) -> bool {
+        self.speed != AnimationSpeed::Instant && !self.reduced_motion
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Effect {
+    // tile flips over; letter status is shown from the halfway point
+    Flip,
+    // typed letter stands out for a moment
+    Pop,
+    // tile jumps up and lands back
+    Bounce,
+}
+
+// animation of a grid cell; animations are time based, so that they take the same time however
+// often the grid is drawn
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellAnimation {
+    pub effect: Effect,
+    pub board: usize,
+    pub row: usize,
+    pub column: usize,
+    pub start: Instant,
+    pub duration: Duration,
+}
+
+impl CellAnimation {
+    // 0.0 until the animation starts, 1.0 once it is over
+    pub fn progress(&self, now: Instant) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+
+        let elapsed = now.saturating_duration_since(self.start);
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    pub fn is_over(&self, now: Instant) -> bool {
+        now >= self.start + self.duration
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Animations {
+    pub settings: AnimationSettings,
+    cells: Vec<CellAnimation>,
+}
+
+impl Animations {
+    pub fn new(settings: AnimationSettings) -> Self {
+        Animations {
+            settings,
+            cells: vec![],
+        }
+    }
+
+    // cell can have one animation at a time; a new one replaces the old
+    // animations are skipped when motion is reduced or the speed is instant
+    pub fn add(&mut self, animation: CellAnimation) {
+        if !self.settings.has_motion() || animation.duration.is_zero() {
+            return;
+        }
+
+        self.cells.retain(|x| {
+            (x.board, x.row, x.column) != (animation.board, animation.row, animation.column)
+        });
+        self.cells.push(animation);
+    }
+
+    // flips of a revealed row, one after the other
+    pub fn flip_row(&mut self, board: usize, row: usize, columns: usize, now: Instant) {
+        for column in 0..columns {
+            self.add(CellAnimation {
+                effect: Effect::Flip,
+                board,
+                row,
+                column,
+                start: now + self.settings.duration(FLIP_STAGGER_MS * column as u64),
+                duration: self.settings.duration(FLIP_MS),
+            });
+        }
+    }
+
+    // bounce wave across the winning row
+    pub fn bounce_row(&mut self, board: usize, row: usize, columns: usize, now: Instant) {
+        for column in 0..columns {
+            self.add(CellAnimation {
+                effect: Effect::Bounce,
+                board,
+                row,
+                column,
+                start: now + self.settings.duration(BOUNCE_STAGGER_MS * column as u64),
+                duration: self.settings.duration(BOUNCE_MS),
+            });
+        }
+    }
+
+    pub fn pop(&mut self, board: usize, row: usize, column: usize, now: Instant) {
+        self.add(CellAnimation {
+            effect: Effect::Pop,
+            board,
+            row,
+            column,
+            start: now,
+            duration: self.settings.duration(POP_MS),
+        });
+    }
+
+    // effect and progress of the cell; animations that have not started yet are at 0.0
+    pub fn cell(
+        &self,
+        board: usize,
+        row: usize,
+        column: usize,
+        now: Instant,
+    ) -> Option<(Effect, f32)> {
+        self.cells
+            .iter()
+            .find(|x| (x.board, x.row, x.column) == (board, row, column) && !x.is_over(now))
+            .map(|x| (x.effect, x.progress(now)))
+    }
+
+    // flips and bounces hold up the game; pops do not
+    pub fn is_blocking(&self, now: Instant) -> bool {
+        self.cells
+            .iter()
+            .any(|x| x.effect != Effect::Pop && !x.is_over(now))
+    }
+
+    // drops the animations that are over
+    pub fn prune(&mut self, now: Instant) {
+        self.cells.retain(|x| !x.is_over(now));
+    }
+
+    // every animation is done at once (eg: skipped with a key press)
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::animation::*;
+
+    #[test]
+    fn test_flip_row() {
+        let now = Instant::now();
+        let mut animations = Animations::default();
+        animations.flip_row(0, 2, 5, now);
+
+        // flips are staggered; later tiles wait at the start
+        assert_eq!(animations.cell(0, 2, 0, now), Some((Effect::Flip, 0.0)));
+        let halfway = now + Duration::from_millis(FLIP_MS / 2);
+        assert_eq!(animations.cell(0, 2, 0, halfway), Some((Effect::Flip, 0.5)));
+        assert_eq!(animations.cell(0, 2, 4, halfway), Some((Effect::Flip, 0.0)));
+        assert_eq!(animations.cell(0, 1, 0, halfway), None);
+        assert!(animations.is_blocking(halfway));
+
+        let end = now + Duration::from_millis(FLIP_STAGGER_MS * 4 + FLIP_MS);
+        assert_eq!(animations.cell(0, 2, 4, end), None);
+        assert!(!animations.is_blocking(end));
+
+        animations.prune(end);
+        assert_eq!(animations, Animations::default());
+    }
+
+    #[test]
+    fn test_pop_and_bounce() {
+        let now = Instant::now();
+        let mut animations = Animations::default();
+
+        // pops do not hold up the game
+        animations.pop(0, 0, 1, now);
+        assert_eq!(animations.cell(0, 0, 1, now), Some((Effect::Pop, 0.0)));
+        assert!(!animations.is_blocking(now));
+
+        // new animation of the cell replaces the old one
+        animations.bounce_row(0, 0, 5, now);
+        assert_eq!(animations.cell(0, 0, 1, now), Some((Effect::Bounce, 0.0)));
+        assert!(animations.is_blocking(now));
+
+        animations.clear();
+        assert!(!animations.is_blocking(now));
+    }
+
+    #[test]
+    fn test_settings() {
+        let now = Instant::now();
+        let slow = AnimationSettings {
+            speed: AnimationSpeed::Slow,
+            reduced_motion: false,
+        };
+        let mut animations = Animations::new(slow);
+        animations.flip_row(0, 0, 5, now);
+        let halfway = now + Duration::from_millis(FLIP_MS);
+        assert_eq!(animations.cell(0, 0, 0, halfway), Some((Effect::Flip, 0.5)));
+
+        let fast = AnimationSettings {
+            speed: AnimationSpeed::Fast,
+            ..slow
+        };
+        assert_eq!(fast.duration(FLIP_MS), Duration::from_millis(FLIP_MS / 2));
+
+        // nothing is animated
+        for settings in [
+            AnimationSettings {
+                speed: AnimationSpeed::Instant,
+                reduced_motion: false,
+            },
+            AnimationSettings {
+                speed: AnimationSpeed::Normal,
+                reduced_motion: true,
+            },
+        ] {
+            assert!(!settings.has_motion());
+            let mut animations = Animations::new(settings);
+            animations.flip_row(0, 0, 5, now);
+            animations.bounce_row(0, 1, 5, now);
+            animations.pop(0, 2, 0, now);
+            assert_eq!(animations.cell(0, 0, 0, now), None);
+            assert!(!animations.is_blocking(now));
+        }
+    }
+}
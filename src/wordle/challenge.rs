@@ -0,0 +1,183 @@
+use super::error::WordlError;
+use super::utils;
+
+// Challenge codes carry a chosen wordle without giving it away. Letters are XOR'd with a key
+// stream, protected by a checksum and written in Crockford base32 (no I, L, O or U)
+// bytes: [version, word length, letters.., checksum (2 bytes)]
+
+const VERSION: u8 = 1;
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const KEY: &[u8] = b"wordl-challenge";
+// characters per group in the code; groups are separated by dashes
+const GROUP_SIZE: usize = 4;
+
+// code for the wordle; wordle must be 4 to 8 ascii letters
+pub fn encode(wordle: &str) -> Result<String, WordlError> {
+    let wordle = wordle.to_lowercase();
+    let length = wordle.chars().count();
+    utils::validate_word_length(length)?;
+    utils::validate_word(&wordle, length)?;
+
+    let mut bytes = vec![VERSION, length as u8];
+    bytes.extend(
+        wordle
+            .bytes()
+            .enumerate()
+            .map(|(index, letter)| (letter - b'a') ^ key(index, length)),
+    );
+    bytes.extend(checksum(&bytes).to_be_bytes());
+
+    let code = to_base32(&bytes);
+    let groups: Vec<&str> = code
+        .as_bytes()
+        .chunks(GROUP_SIZE)
+        .map(|group| std::str::from_utf8(group).unwrap_or_default())
+        .collect();
+
+    Ok(groups.join("-"))
+}
+
+// wordle of the code; dashes, spaces and case are ignored
+pub fn decode(code: &str) -> Result<String, WordlError> {
+    let bytes = from_base32(code).ok_or(WordlError::InvalidChallengeCode)?;
+
+    let (payload, sum) = bytes
+        .split_last_chunk::<2>()
+        .ok_or(WordlError::InvalidChallengeCode)?;
+    if u16::from_be_bytes(*sum) != checksum(payload) {
+        return Err(WordlError::InvalidChallengeCode);
+    }
+
+    let [version, length, letters @ ..] = payload else {
+        return Err(WordlError::InvalidChallengeCode);
+    };
+    let length = usize::from(*length);
+    if *version != VERSION
+        || letters.len() != length
+        || utils::validate_word_length(length).is_err()
+    {
+        return Err(WordlError::InvalidChallengeCode);
+    }
+
+    letters
+        .iter()
+        .enumerate()
+        .map(|(index, letter)| {
+            let letter = letter ^ key(index, length);
+            (letter < 26)
+                .then_some(char::from(b'a' + letter))
+                .ok_or(WordlError::InvalidChallengeCode)
+        })
+        .collect()
+}
+
+// key stream byte; depends on the word length too, so that words sharing a prefix do not share
+// the start of the code
+fn key(index: usize, length: usize) -> u8 {
+    KEY[index % KEY.len()].wrapping_add((index * 7 + length * 13) as u8)
+}
+
+// fnv-1a folded to 16 bits
+fn checksum(bytes: &[u8]) -> u16 {
+    let hash = bytes.iter().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(0x0100_0193)
+    });
+
+    ((hash >> 16) ^ (hash & 0xffff)) as u16
+}
+
+fn to_base32(bytes: &[u8]) -> String {
+    let mut code = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for byte in bytes {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            code.push(char::from(ALPHABET[((buffer >> bits) & 31) as usize]));
+        }
+    }
+    if bits > 0 {
+        code.push(char::from(ALPHABET[((buffer << (5 - bits)) & 31) as usize]));
+    }
+
+    code
+}
+
+// `None` if the code has characters outside the alphabet
+fn from_base32(code: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for character in code.chars().filter(|x| *x != '-' && !x.is_whitespace()) {
+        // commonly confused characters are read as the digits they look like
+        let character = match character.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            other => other,
+        };
+        let value = ALPHABET.iter().position(|x| char::from(*x) == character)?;
+
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::challenge::*;
+
+    #[test]
+    fn test_encode_decode() {
+        for wordle in ["plumb", "pious", "abbey", "crane", "zzzz", "aaaaaaaa"] {
+            let code = encode(wordle).unwrap();
+            assert!(!code.to_lowercase().contains(wordle));
+            assert_eq!(decode(&code).unwrap(), wordle);
+        }
+
+        // words sharing letters do not share the code
+        assert_ne!(encode("crane").unwrap(), encode("crank").unwrap());
+        assert_eq!(encode("PLUMB").unwrap(), encode("plumb").unwrap());
+
+        // case, dashes and spaces are ignored
+        let code = encode("plumb").unwrap();
+        let loose = code.replace('-', " ").to_lowercase();
+        assert_eq!(decode(&loose).unwrap(), "plumb");
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        assert_eq!(encode("abc"), Err(WordlError::UnsupportedLength(3)));
+        assert_eq!(encode("ab1de"), Err(WordlError::NonAlphabetic('1')));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let code = encode("plumb").unwrap();
+
+        // tampered code
+        let mut tampered: Vec<char> = code.chars().collect();
+        tampered[2] = if tampered[2] == 'A' { 'B' } else { 'A' };
+        let tampered: String = tampered.into_iter().collect();
+        assert_eq!(decode(&tampered), Err(WordlError::InvalidChallengeCode));
+
+        // incomplete code
+        assert_eq!(
+            decode(&code[..code.len() - 3]),
+            Err(WordlError::InvalidChallengeCode)
+        );
+
+        assert_eq!(decode(""), Err(WordlError::InvalidChallengeCode));
+        assert_eq!(decode("plumb!"), Err(WordlError::InvalidChallengeCode));
+        assert_eq!(decode("UUUU"), Err(WordlError::InvalidChallengeCode));
+    }
+}
@@ -0,0 +1,146 @@
+use chrono::{Local, NaiveDate, NaiveTime};
+use std::time::Duration;
+
+use super::utils;
+
+// date of the very first daily wordle; puzzle numbers are counted from this date
+const DAILY_EPOCH: (i32, u32, u32) = (2021, 6, 19);
+
+// puzzle number for the given date (number of days since the daily epoch)
+pub fn puzzle_number(date: NaiveDate) -> usize {
+    let (year, month, day) = DAILY_EPOCH;
+    let epoch = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+    date.signed_duration_since(epoch).num_days().max(0) as usize
+}
+
+// puzzle number for the current local date
+pub fn todays_puzzle_number() -> usize {
+    puzzle_number(Local::now().date_naive())
+}
+
+// daily wordle is derived only from the puzzle number and the answer list, so everyone running the
+// same version plays the same word on the same day
+pub fn daily_wordle<W: AsRef<str>>(valid_wordles: &[W], number: usize) -> String {
+    let index = daily_index(number, 0, 0, valid_wordles.len());
+
+    valid_wordles[index].as_ref().to_string()
+}
+
+// daily wordles for multi board modes; first board is the daily wordle, and the wordles of a day
+// are all different (as long as there are enough answers)
+pub fn daily_wordles<W: AsRef<str>>(
+    valid_wordles: &[W],
+    number: usize,
+    count: usize,
+) -> Vec<String> {
+    let mut indices: Vec<usize> = Vec::with_capacity(count);
+
+    for board in 0..count {
+        // board that lands on a wordle of an earlier board is hashed again
+        let index = (0..)
+            .map(|retry| daily_index(number, board, retry, valid_wordles.len()))
+            .find(|index| !indices.contains(index) || indices.len() >= valid_wordles.len())
+            .unwrap_or_default();
+        indices.push(index);
+    }
+
+    indices
+        .into_iter()
+        .map(|index| valid_wordles[index].as_ref().to_string())
+        .collect()
+}
+
+// answer of a board of the day; hashed, so that it does not depend on the length of the answer list
+fn daily_index(number: usize, board: usize, retry: usize, length: usize) -> usize {
+    let bytes = [number as u64, board as u64, retry as u64]
+        .into_iter()
+        .flat_map(u64::to_le_bytes);
+
+    (utils::fnv1a(bytes) % length as u64) as usize
+}
+
+// time left until the next daily puzzle (local midnight)
+pub fn time_until_next_puzzle() -> Duration {
+    let now = Local::now().naive_local();
+    let next_midnight = now.date().succ_opt().unwrap().and_time(NaiveTime::MIN);
+
+    (next_midnight - now).to_std().unwrap_or_default()
+}
+
+// formats duration as hh:mm:ss countdown
+pub fn format_countdown(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::daily::*;
+
+    #[test]
+    fn test_puzzle_number() {
+        let epoch = NaiveDate::from_ymd_opt(2021, 6, 19).unwrap();
+        assert_eq!(puzzle_number(epoch), 0);
+
+        let next_day = NaiveDate::from_ymd_opt(2021, 6, 20).unwrap();
+        assert_eq!(puzzle_number(next_day), 1);
+
+        // dates before the epoch are clamped to the first puzzle
+        let before_epoch = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        assert_eq!(puzzle_number(before_epoch), 0);
+    }
+
+    #[test]
+    fn test_daily_wordle() {
+        let words = crate::wordle::data::valid_wordles();
+
+        // same puzzle number always gives the same word
+        assert_eq!(daily_wordle(&words, 42), daily_wordle(&words, 42));
+        assert_ne!(daily_wordle(&words, 42), daily_wordle(&words, 43));
+
+        // word changes every day, whatever the length of the answer list
+        let words: Vec<String> = (0..7919 * 2).map(|x| x.to_string()).collect();
+        let mut week: Vec<String> = (0..7).map(|number| daily_wordle(&words, number)).collect();
+        week.sort();
+        week.dedup();
+        assert_eq!(week.len(), 7);
+    }
+
+    #[test]
+    fn test_daily_wordles() {
+        let words = crate::wordle::data::valid_wordles();
+
+        let wordles = daily_wordles(&words, 42, 4);
+        assert_eq!(wordles, daily_wordles(&words, 42, 4));
+        assert_eq!(wordles.len(), 4);
+        for (index, wordle) in wordles.iter().enumerate() {
+            assert!(!wordles[index + 1..].contains(wordle));
+        }
+
+        // first board is the regular daily wordle; next day has other wordles
+        assert_eq!(daily_wordles(&words, 42, 1), vec![daily_wordle(&words, 42)]);
+        let next_day = daily_wordles(&words, 43, 4);
+        assert!(wordles.iter().all(|wordle| !next_day.contains(wordle)));
+
+        // every answer is used once before boards share a wordle
+        let words = vec!["aback", "abase"];
+        let mut wordles = daily_wordles(&words, 42, 2);
+        wordles.sort();
+        assert_eq!(wordles, words);
+        assert_eq!(daily_wordles(&words, 42, 3).len(), 3);
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(0)), "00:00:00");
+        assert_eq!(format_countdown(Duration::from_secs(3661)), "01:01:01");
+        assert_eq!(format_countdown(Duration::from_secs(86399)), "23:59:59");
+    }
+}
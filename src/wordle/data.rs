@@ -1,13 +1,564 @@
-pub fn valid_guesses() -> Vec<String> {
-    let file_data = include_str!("./files/guess.txt");
-    let words = file_data.split("\n");
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use super::dictionary::{self, Dictionary};
+use super::error::WordlError;
+use super::utils;
+use crate::storage;
 
-    words.map(|x| x.to_string()).collect()
+pub fn valid_guesses() -> Vec<String> {
+    dictionary::GUESSES.iter().map(|x| x.to_string()).collect()
 }
 
 pub fn valid_wordles() -> Vec<String> {
-    let file_data = include_str!("./files/answer.txt");
-    let words = file_data.split("\n");
+    dictionary::ANSWERS.iter().map(|x| x.to_string()).collect()
+}
+
+// list of words; bundled lists are used in place, without copying the words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordList {
+    Bundled(&'static [&'static str]),
+    Owned(Vec<String>),
+}
+
+impl WordList {
+    pub fn len(&self) -> usize {
+        match self {
+            WordList::Bundled(words) => words.len(),
+            WordList::Owned(words) => words.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        let (bundled, owned) = match self {
+            WordList::Bundled(words) => (*words, &[][..]),
+            WordList::Owned(words) => (&[][..], words.as_slice()),
+        };
+
+        bundled
+            .iter()
+            .copied()
+            .chain(owned.iter().map(String::as_str))
+    }
+
+    // words as a slice; borrowed for bundled lists, only the references are collected otherwise
+    pub fn as_strs(&self) -> Cow<'_, [&str]> {
+        match self {
+            WordList::Bundled(words) => Cow::Borrowed(*words),
+            WordList::Owned(words) => Cow::Owned(words.iter().map(String::as_str).collect()),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<String> {
+        self.iter().map(str::to_string).collect()
+    }
+}
+
+impl Default for WordList {
+    fn default() -> Self {
+        WordList::Owned(vec![])
+    }
+}
+
+impl From<Vec<String>> for WordList {
+    fn from(words: Vec<String>) -> Self {
+        WordList::Owned(words)
+    }
+}
+
+// answers and allowed guesses for a word length
+#[derive(Debug, Clone, Default)]
+pub struct WordLists {
+    pub valid_wordles: WordList,
+    pub valid_guesses: WordList,
+    // lookup of both lists; use `WordLists::new` to build it along with the lists
+    pub dictionary: Dictionary,
+}
+
+impl WordLists {
+    pub fn new(valid_wordles: Vec<String>, valid_guesses: Vec<String>) -> Self {
+        WordLists {
+            dictionary: Dictionary::from_lists(&valid_wordles, &valid_guesses),
+            valid_wordles: valid_wordles.into(),
+            valid_guesses: valid_guesses.into(),
+        }
+    }
+
+    // bundled 5 letter lists; nothing is allocated
+    pub fn bundled() -> Self {
+        WordLists {
+            valid_wordles: WordList::Bundled(&dictionary::ANSWERS),
+            valid_guesses: WordList::Bundled(&dictionary::GUESSES),
+            dictionary: Dictionary::bundled(),
+        }
+    }
+}
+
+// word list files given by the user; bundled (or data directory) lists are used for the ones not
+// given
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WordListSource {
+    pub answers: Option<PathBuf>,
+    pub guesses: Option<PathBuf>,
+}
+
+// problem found in a word list file; line numbers start at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordListIssue {
+    BlankLine(usize),
+    Duplicate(usize, String),
+    WrongLength(usize, String),
+    NonAlphabetic(usize, String),
+    // answer that is not in the guess list; it could never be guessed
+    NotGuessable(String),
+}
+
+// issue with the file it was found in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordListReport {
+    pub path: String,
+    pub issue: WordListIssue,
+}
+
+impl WordListIssue {
+    // errors make the list unusable; blank lines and duplicates are only reported
+    pub fn is_error(&self) -> bool {
+        !matches!(
+            self,
+            WordListIssue::BlankLine(_) | WordListIssue::Duplicate(_, _)
+        )
+    }
+}
+
+impl fmt::Display for WordListIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordListIssue::BlankLine(line) => write!(f, "line {}: blank line", line),
+            WordListIssue::Duplicate(line, word) => {
+                write!(f, "line {}: duplicate word '{}'", line, word)
+            }
+            WordListIssue::WrongLength(line, word) => write!(
+                f,
+                "line {}: '{}' has {} letters",
+                line,
+                word,
+                word.chars().count()
+            ),
+            WordListIssue::NonAlphabetic(line, word) => {
+                write!(
+                    f,
+                    "line {}: '{}' has characters other than letters",
+                    line, word
+                )
+            }
+            WordListIssue::NotGuessable(word) => {
+                write!(f, "answer '{}' is not in the guess list", word)
+            }
+        }
+    }
+}
+
+impl fmt::Display for WordListReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.issue)
+    }
+}
+
+// directory of a named word list pack; $XDG_CONFIG_HOME/wordl/packs/<name>
+// packs have answers.txt and optional guesses.txt
+pub fn pack_source(name: &str) -> Result<WordListSource, WordlError> {
+    validate_pack_name(name)?;
+
+    let directory = storage::config_file(&format!("packs/{}", name));
+    let answers = directory.as_ref().map(|dir| dir.join("answers.txt"));
+
+    match answers {
+        Some(answers) if answers.is_file() => {
+            let guesses = directory
+                .map(|dir| dir.join("guesses.txt"))
+                .filter(|path| path.is_file());
+
+            Ok(WordListSource {
+                answers: Some(answers),
+                guesses,
+            })
+        }
+        _ => Err(WordlError::MissingPack {
+            name: name.to_string(),
+            path: answers
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| format!("<config directory>/wordl/packs/{}/answers.txt", name)),
+        }),
+    }
+}
+
+// pack is a single directory inside the packs directory; names that would lead out of it are
+// rejected
+fn validate_pack_name(name: &str) -> Result<(), WordlError> {
+    // a single plain component; no roots, drive prefixes or `.`/`..`
+    let mut components = Path::new(name).components();
+    let is_single_component = matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    );
+
+    if is_single_component && !name.contains(['/', '\\']) && !name.contains("..") {
+        Ok(())
+    } else {
+        Err(WordlError::InvalidPackName(name.to_string()))
+    }
+}
+
+// word lists from the user's files, falling back to the default lists for the ones not given
+// lists with errors are rejected; other issues are returned to be shown to the user
+pub fn load_word_lists(
+    source: &WordListSource,
+    length: usize,
+) -> Result<(WordLists, Vec<WordListReport>), WordlError> {
+    utils::validate_word_length(length)?;
+
+    let mut reports = Vec::new();
+    // answers are the only list that has to be there; other read errors are reported as they are
+    let mut read_list = |path: &Path, is_answers: bool| -> Result<Vec<String>, WordlError> {
+        let data = fs::read_to_string(path).map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound if is_answers => WordlError::MissingWordList {
+                length,
+                path: path.display().to_string(),
+            },
+            _ => WordlError::UnreadableWordList {
+                path: path.display().to_string(),
+                error: error.into(),
+            },
+        })?;
+        let (words, issues) = check_word_list(&data, length);
+        reports.extend(issues.into_iter().map(|issue| WordListReport {
+            path: path.display().to_string(),
+            issue,
+        }));
+
+        Ok(words)
+    };
+
+    let custom_wordles = source
+        .answers
+        .as_deref()
+        .map(|path| read_list(path, true))
+        .transpose()?;
+    let custom_guesses = source
+        .guesses
+        .as_deref()
+        .map(|path| read_list(path, false))
+        .transpose()?;
+
+    let valid_wordles = match custom_wordles {
+        Some(words) => words,
+        None => word_lists(length)?.valid_wordles.to_vec(),
+    };
+    let valid_guesses = match custom_guesses {
+        Some(words) => {
+            // every answer has to be a legal guess
+            let guesses: HashSet<&String> = words.iter().collect();
+            if let Some(path) = &source.guesses {
+                reports.extend(
+                    valid_wordles
+                        .iter()
+                        .filter(|word| !guesses.contains(word))
+                        .map(|word| WordListReport {
+                            path: path.display().to_string(),
+                            issue: WordListIssue::NotGuessable(word.to_string()),
+                        }),
+                );
+            }
+            words
+        }
+        // answers are always legal guesses; no need for a guess list
+        None => word_lists(length)
+            .map(|lists| lists.valid_guesses.to_vec())
+            .unwrap_or_default(),
+    };
+
+    let (errors, warnings): (Vec<_>, Vec<_>) = reports
+        .into_iter()
+        .partition(|report| report.issue.is_error());
+    if !errors.is_empty() {
+        return Err(WordlError::InvalidWordList(errors));
+    }
+
+    if valid_wordles.is_empty() {
+        return Err(WordlError::MissingWordList {
+            length,
+            path: source
+                .answers
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_default(),
+        });
+    }
+
+    Ok((WordLists::new(valid_wordles, valid_guesses), warnings))
+}
+
+// word lists for the given word length
+// 5 letter lists are bundled; other lengths are loaded from the data directory
+// $XDG_DATA_HOME/wordl/words/<length>/answers.txt and optional guesses.txt
+pub fn word_lists(length: usize) -> Result<WordLists, WordlError> {
+    utils::validate_word_length(length)?;
+
+    if length == utils::WORD_LENGTH {
+        return Ok(WordLists::bundled());
+    }
+
+    let directory = format!("words/{}", length);
+    let answers_path = storage::data_file(&format!("{}/answers.txt", directory));
+    let guesses_path = storage::data_file(&format!("{}/guesses.txt", directory));
+
+    let valid_wordles = answers_path
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|data| parse_word_list(&data, length))
+        .unwrap_or_default();
+    if valid_wordles.is_empty() {
+        return Err(WordlError::MissingWordList {
+            length,
+            path: answers_path
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| format!("<data directory>/wordl/{}/answers.txt", directory)),
+        });
+    }
+
+    let valid_guesses = guesses_path
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|data| parse_word_list(&data, length))
+        .unwrap_or_default();
+
+    Ok(WordLists::new(valid_wordles, valid_guesses))
+}
+
+// one word per line; words with the wrong length or non letters are skipped
+fn parse_word_list(data: &str, length: usize) -> Vec<String> {
+    data.lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|word| utils::validate_word(word, length).is_ok())
+        .collect()
+}
+
+// one word per line; valid words are kept (once) and every problem is reported
+fn check_word_list(data: &str, length: usize) -> (Vec<String>, Vec<WordListIssue>) {
+    let mut words = Vec::new();
+    let mut seen = HashSet::new();
+    let mut issues = Vec::new();
+
+    for (index, line) in data.lines().enumerate() {
+        let line_number = index + 1;
+        let word = line.trim().to_lowercase();
+
+        let issue = match utils::validate_word(&word, length) {
+            _ if word.is_empty() => Some(WordListIssue::BlankLine(line_number)),
+            Err(WordlError::WrongLength { .. }) => {
+                Some(WordListIssue::WrongLength(line_number, word.to_string()))
+            }
+            Err(_) => Some(WordListIssue::NonAlphabetic(line_number, word.to_string())),
+            Ok(_) if !seen.insert(word.to_string()) => {
+                Some(WordListIssue::Duplicate(line_number, word.to_string()))
+            }
+            Ok(_) => None,
+        };
+
+        match issue {
+            Some(issue) => issues.push(issue),
+            None => words.push(word),
+        }
+    }
+
+    (words, issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::data::*;
+
+    #[test]
+    fn test_parse_word_list() {
+        let data = "wolf\r\nFLOW\n\n  loll \npious\nab1c\n";
+        assert_eq!(parse_word_list(data, 4), vec!["wolf", "flow", "loll"]);
+        assert_eq!(parse_word_list(data, 5), vec!["pious"]);
+    }
+
+    #[test]
+    fn test_check_word_list() {
+        let data = "wolf\n\nFlow\nwolf\nab1c\npious\n";
+        let (words, issues) = check_word_list(data, 4);
+        assert_eq!(words, vec!["wolf", "flow"]);
+        assert_eq!(
+            issues,
+            vec![
+                WordListIssue::BlankLine(2),
+                WordListIssue::Duplicate(4, "wolf".into()),
+                WordListIssue::NonAlphabetic(5, "ab1c".into()),
+                WordListIssue::WrongLength(6, "pious".into()),
+            ]
+        );
+        assert_eq!(
+            issues[2].to_string(),
+            "line 5: 'ab1c' has characters other than letters"
+        );
+        assert!(!issues[1].is_error());
+        assert!(issues[3].is_error());
+    }
+
+    fn write_list(name: &str, data: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("wordl-{}-{}", std::process::id(), name));
+        fs::write(&path, data).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_word_lists() {
+        let answers = write_list("answers.txt", "wolf\nflow\n\nflow\n");
+        let guesses = write_list("guesses.txt", "wolf\nflow\nfowl\n");
+        let source = WordListSource {
+            answers: Some(answers.clone()),
+            guesses: Some(guesses.clone()),
+        };
+
+        // blank lines and duplicates are reported, but the lists are usable
+        let (lists, warnings) = load_word_lists(&source, 4).unwrap();
+        assert_eq!(lists.valid_wordles.to_vec(), vec!["wolf", "flow"]);
+        assert_eq!(lists.valid_guesses.to_vec(), vec!["wolf", "flow", "fowl"]);
+        assert_eq!(
+            warnings.iter().map(|x| x.issue.clone()).collect::<Vec<_>>(),
+            vec![
+                WordListIssue::BlankLine(3),
+                WordListIssue::Duplicate(4, "flow".into())
+            ]
+        );
+
+        // answers must be legal guesses
+        let guesses = write_list("short-guesses.txt", "wolf\n");
+        let source = WordListSource {
+            answers: Some(answers.clone()),
+            guesses: Some(guesses.clone()),
+        };
+        match load_word_lists(&source, 4).unwrap_err() {
+            WordlError::InvalidWordList(errors) => {
+                assert_eq!(errors[0].issue, WordListIssue::NotGuessable("flow".into()))
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+
+        // entries must have the right length
+        let source = WordListSource {
+            answers: Some(answers.clone()),
+            guesses: None,
+        };
+        assert!(matches!(
+            load_word_lists(&source, 5),
+            Err(WordlError::InvalidWordList(_))
+        ));
+
+        // only guesses given; bundled answers are used
+        let five_letter_guesses = write_list("guesses-5.txt", "pious\n");
+        let source = WordListSource {
+            answers: None,
+            guesses: Some(five_letter_guesses.clone()),
+        };
+        let error = load_word_lists(&source, 5).unwrap_err();
+        assert!(matches!(error, WordlError::InvalidWordList(_)));
+
+        for path in [answers, guesses, five_letter_guesses] {
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_load_word_lists_read_errors() {
+        let missing = std::env::temp_dir().join(format!("wordl-{}-missing", std::process::id()));
+        let not_utf8 = std::env::temp_dir().join(format!("wordl-{}-latin1", std::process::id()));
+        fs::write(&not_utf8, b"caf\xe9\n").unwrap();
+
+        // missing answers can be added
+        let source = WordListSource {
+            answers: Some(missing.clone()),
+            guesses: None,
+        };
+        assert!(matches!(
+            load_word_lists(&source, 4).unwrap_err(),
+            WordlError::MissingWordList { length: 4, .. }
+        ));
+
+        // any other problem is reported with the file and the io error
+        for (answers, guesses, kind) in [
+            (None, Some(missing.clone()), io::ErrorKind::NotFound),
+            (Some(not_utf8.clone()), None, io::ErrorKind::InvalidData),
+        ] {
+            let source = WordListSource { answers, guesses };
+            match load_word_lists(&source, 4).unwrap_err() {
+                WordlError::UnreadableWordList { path, error } => {
+                    assert!(path.starts_with(&std::env::temp_dir().display().to_string()));
+                    assert_eq!(error.0.kind(), kind);
+                }
+                error => panic!("unexpected error {:?}", error),
+            }
+        }
+
+        fs::remove_file(not_utf8).unwrap();
+    }
+
+    #[test]
+    fn test_pack_name() {
+        for name in [
+            "",
+            ".",
+            "..",
+            "../words",
+            "../../etc",
+            "a/b",
+            "a\\b",
+            "a..b",
+            "/tmp",
+        ] {
+            assert_eq!(
+                pack_source(name).unwrap_err(),
+                WordlError::InvalidPackName(name.to_string())
+            );
+        }
+
+        // plain names are looked up in the packs directory
+        assert!(matches!(
+            pack_source("wordl-missing-pack").unwrap_err(),
+            WordlError::MissingPack { .. }
+        ));
+    }
+
+    #[test]
+    fn test_word_lists() {
+        let lists = word_lists(5).unwrap();
+        assert_eq!(lists.valid_wordles.to_vec(), valid_wordles());
+        assert_eq!(lists.valid_guesses.to_vec(), valid_guesses());
+
+        assert_eq!(word_lists(3).unwrap_err(), WordlError::UnsupportedLength(3));
+        assert_eq!(word_lists(9).unwrap_err(), WordlError::UnsupportedLength(9));
+    }
+
+    #[test]
+    fn test_word_list() {
+        // bundled lists are used in place
+        let lists = WordLists::bundled();
+        assert!(matches!(lists.valid_wordles, WordList::Bundled(_)));
+        assert!(matches!(lists.valid_wordles.as_strs(), Cow::Borrowed(_)));
+        assert_eq!(lists.valid_wordles.len(), valid_wordles().len());
 
-    words.map(|x| x.to_string()).collect()
+        let list = WordList::from(vec!["wolf".to_string(), "flow".to_string()]);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec!["wolf", "flow"]);
+        assert_eq!(*list.as_strs(), ["wolf", "flow"]);
+        assert!(WordList::default().is_empty());
+    }
 }
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+// bundled word lists packed at build time (ANSWERS, GUESSES and DICTIONARY); see build.rs
+include!(concat!(env!("OUT_DIR"), "/words.rs"));
+
+// allowed words lookup; bundled lists need no allocation, and are binary searched
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    bundled: bool,
+    // words of lists that are not bundled (data directory or user's files)
+    words: HashSet<String>,
+}
+
+impl Dictionary {
+    // bundled 5 letter answers and guesses
+    pub fn bundled() -> Self {
+        Dictionary {
+            bundled: true,
+            words: HashSet::new(),
+        }
+    }
+
+    pub fn from_lists(valid_wordles: &[String], valid_guesses: &[String]) -> Self {
+        Dictionary {
+            bundled: false,
+            words: valid_wordles.iter().chain(valid_guesses).cloned().collect(),
+        }
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        (self.bundled && is_bundled_word(word)) || self.words.contains(word)
+    }
+}
+
+fn is_bundled_word(word: &str) -> bool {
+    word.as_bytes()
+        .try_into()
+        .is_ok_and(|word: [u8; 5]| DICTIONARY.binary_search(&word).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::dictionary::*;
+
+    #[test]
+    fn test_bundled() {
+        let dictionary = Dictionary::bundled();
+        for word in ANSWERS.iter().chain(GUESSES.iter()) {
+            assert!(dictionary.contains(word), "{} is missing", word);
+        }
+
+        assert!(dictionary.contains("pious"));
+        assert!(!dictionary.contains("xxxxx"));
+        assert!(!dictionary.contains("pio"));
+        assert!(!dictionary.contains("piouss"));
+        assert!(!dictionary.contains(""));
+        assert!(DICTIONARY.windows(2).all(|x| x[0] < x[1]));
+    }
+
+    #[test]
+    fn test_from_lists() {
+        let dictionary = Dictionary::from_lists(&["wolf".into()], &["fowl".into()]);
+        assert!(dictionary.contains("wolf"));
+        assert!(dictionary.contains("fowl"));
+        assert!(!dictionary.contains("flow"));
+        // bundled words are not allowed in other lists
+        assert!(!dictionary.contains("pious"));
+        assert!(!Dictionary::default().contains("pious"));
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+use super::data::WordListReport;
+use super::game::{BOARD_COUNTS, MAX_ATTEMPTS, MIN_ATTEMPTS};
+
+// word list problems listed in the error message; rest are counted
+const MAX_LISTED_ISSUES: usize = 10;
+
+/// Errors returned by the wordle engine
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WordlError {
+    /// guess (or wordle) does not have the expected number of letters
+    WrongLength { expected: usize, actual: usize },
+    /// wordle length is not supported (4 to 8 letters)
+    UnsupportedLength(usize),
+    /// word list for the word length is not bundled, and not present in the data directory
+    MissingWordList { length: usize, path: String },
+    /// word list file cannot be read (eg: permission denied, not utf-8, or a missing guess list)
+    UnreadableWordList { path: String, error: IoError },
+    /// word list pack is not present in the config directory
+    MissingPack { name: String, path: String },
+    /// word list pack name is a path (eg: `../words`); packs are looked up by name only
+    InvalidPackName(String),
+    /// word list files have errors; every error found is listed
+    InvalidWordList(Vec<WordListReport>),
+    /// number of allowed attempts is not supported
+    UnsupportedAttempts(usize),
+    /// number of boards is not supported (1, 2, 4 or 8)
+    UnsupportedBoards(usize),
+    /// guess has a character that is not an ascii letter
+    NonAlphabetic(char),
+    /// guess is not present in the word lists
+    NotInWordList(String),
+    /// hard mode is on and the guess does not use the revealed hints
+    HardModeViolation(HardModeViolation),
+    /// no more guesses are allowed
+    GameOver,
+    /// challenge code cannot be decoded; incomplete or tampered
+    InvalidChallengeCode,
+    /// challenge games have a single chosen wordle; not playable with the other modes
+    UnsupportedChallengeMode,
+    /// challenge wordle is not in the answer list; code was made with other lists (eg: a pack)
+    ChallengeNotInWordList,
+    /// key binding cannot be parsed (eg: `ctrl-`)
+    InvalidKeyBinding(String),
+    /// key is used to type guesses, or always quits
+    ReservedKeyBinding(String),
+    /// key is bound to more than one action
+    DuplicateKeyBinding(String),
+}
+
+/// IO error shared by the clones of an error; errors are equal if they are of the same kind
+#[derive(Clone, Debug)]
+pub struct IoError(pub Arc<io::Error>);
+
+impl From<io::Error> for IoError {
+    fn from(error: io::Error) -> Self {
+        IoError(Arc::new(error))
+    }
+}
+
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.kind() == other.0.kind()
+    }
+}
+
+impl Eq for IoError {}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Revealed hint that was not used in a hard mode guess
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HardModeViolation {
+    /// correct (green) letter is not in its position; position is zero based
+    CorrectLetter { position: usize, letter: char },
+    /// present (yellow) letter is not used
+    PresentLetter { letter: char },
+}
+
+impl fmt::Display for WordlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordlError::WrongLength { expected, actual } => {
+                write!(f, "Guess must have {} letters, found {}", expected, actual)
+            }
+            WordlError::UnsupportedLength(length) => {
+                write!(
+                    f,
+                    "{} letter words are not supported; use 4 to 8 letters",
+                    length
+                )
+            }
+            WordlError::UnsupportedAttempts(attempts) => write!(
+                f,
+                "{} attempts are not supported; use {} to {} attempts",
+                attempts, MIN_ATTEMPTS, MAX_ATTEMPTS
+            ),
+            WordlError::UnsupportedBoards(boards) => write!(
+                f,
+                "{} boards are not supported; use {}",
+                boards,
+                BOARD_COUNTS.map(|x| x.to_string()).join(", ")
+            ),
+            WordlError::MissingWordList { length, path } => write!(
+                f,
+                "Word list for {} letter words not found. Add answers (one word per line) to {}",
+                length, path
+            ),
+            WordlError::UnreadableWordList { path, error } => {
+                write!(f, "Word list {} cannot be read: {}", path, error)
+            }
+            WordlError::MissingPack { name, path } => write!(
+                f,
+                "Word list pack '{}' not found. Add answers (one word per line) to {}",
+                name, path
+            ),
+            WordlError::InvalidPackName(name) => write!(
+                f,
+                "'{}' is not a pack name; use the name of a directory in <config directory>/wordl/packs",
+                name
+            ),
+            WordlError::InvalidWordList(reports) => {
+                write!(f, "Invalid word list")?;
+                for report in reports.iter().take(MAX_LISTED_ISSUES) {
+                    write!(f, "\n  {}", report)?;
+                }
+                if reports.len() > MAX_LISTED_ISSUES {
+                    write!(f, "\n  and {} more", reports.len() - MAX_LISTED_ISSUES)?;
+                }
+                Ok(())
+            }
+            WordlError::NonAlphabetic(character) => {
+                write!(f, "'{}' is not a letter", character)
+            }
+            WordlError::NotInWordList(_) => write!(f, "Not in word list"),
+            WordlError::HardModeViolation(violation) => write!(f, "{}", violation),
+            WordlError::GameOver => write!(f, "Game is already over"),
+            WordlError::InvalidChallengeCode => write!(
+                f,
+                "Invalid challenge code; make sure it is copied completely"
+            ),
+            WordlError::UnsupportedChallengeMode => write!(
+                f,
+                "Challenge codes cannot be combined with daily, absurdle, multi board, speedrun or survival modes"
+            ),
+            WordlError::ChallengeNotInWordList => write!(
+                f,
+                "Challenge wordle is not in the answer list; play it with the word lists it was created with (--pack, --answers or --words)"
+            ),
+            WordlError::InvalidKeyBinding(binding) => write!(
+                f,
+                "'{}' is not a key binding; use a symbol, ctrl-<key>, esc, tab or f1 to f12",
+                binding
+            ),
+            WordlError::ReservedKeyBinding(binding) => write!(
+                f,
+                "'{}' cannot be bound; letters, enter and backspace type the guess, and ctrl-c quits",
+                binding
+            ),
+            WordlError::DuplicateKeyBinding(binding) => {
+                write!(f, "'{}' is bound to more than one action", binding)
+            }
+        }
+    }
+}
+
+impl fmt::Display for HardModeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardModeViolation::CorrectLetter { position, letter } => write!(
+                f,
+                "{} letter must be {}",
+                ordinal(position + 1),
+                letter.to_ascii_uppercase()
+            ),
+            HardModeViolation::PresentLetter { letter } => {
+                write!(f, "Guess must contain {}", letter.to_ascii_uppercase())
+            }
+        }
+    }
+}
+
+impl std::error::Error for WordlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WordlError::UnreadableWordList { error, .. } => Some(error.0.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+// 1 => 1st, 2 => 2nd etc
+fn ordinal(number: usize) -> String {
+    let suffix = match (number % 10, number % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{}{}", number, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::error::*;
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(4), "4th");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(22), "22nd");
+    }
+
+    #[test]
+    fn test_error_messages() {
+        let error = WordlError::WrongLength {
+            expected: 5,
+            actual: 3,
+        };
+        assert_eq!(error.to_string(), "Guess must have 5 letters, found 3");
+
+        let error = WordlError::HardModeViolation(HardModeViolation::CorrectLetter {
+            position: 1,
+            letter: 'r',
+        });
+        assert_eq!(error.to_string(), "2nd letter must be R");
+
+        let error = WordlError::HardModeViolation(HardModeViolation::PresentLetter { letter: 'u' });
+        assert_eq!(error.to_string(), "Guess must contain U");
+
+        let error = WordlError::NotInWordList("xxxxx".into());
+        assert_eq!(error.to_string(), "Not in word list");
+
+        let error = WordlError::UnsupportedAttempts(0);
+        assert_eq!(
+            error.to_string(),
+            "0 attempts are not supported; use 1 to 13 attempts"
+        );
+
+        let error = WordlError::UnsupportedBoards(3);
+        assert_eq!(
+            error.to_string(),
+            "3 boards are not supported; use 1, 2, 4, 8"
+        );
+
+        let error = WordlError::UnsupportedLength(9);
+        assert_eq!(
+            error.to_string(),
+            "9 letter words are not supported; use 4 to 8 letters"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use rand::seq::SliceRandom;
+
+use super::analysis::{self, Analysis};
+use super::data::{WordList, WordLists};
+use super::dictionary::Dictionary;
+use super::error::WordlError;
+use super::model::{GameResult, KeyboardHints, LetterStatus};
+use super::solver::{self, Hints};
+use crate::wordle;
+
+/// Error of `Game::guess`; kept under its first name so that code written against it still builds
+pub type GuessError = WordlError;
+
+// default number of guesses allowed in a game
+pub const DEFAULT_ATTEMPTS: usize = 6;
+// supported number of guesses allowed in a game
+pub const MIN_ATTEMPTS: usize = 1;
+pub const MAX_ATTEMPTS: usize = 13;
+// supported number of boards; single board, dordle, quordle and octordle
+pub const BOARD_COUNTS: [usize; 4] = [1, 2, 4, 8];
+
+// name of the multi board mode; `None` for single board games
+pub fn board_mode_name(boards: usize) -> Option<&'static str> {
+    match boards {
+        2 => Some("Dordle"),
+        4 => Some("Quordle"),
+        8 => Some("Octordle"),
+        _ => None,
+    }
+}
+
+// boards are tiled 2 per row (4 per row for octordle)
+pub fn board_columns(boards: usize) -> usize {
+    if boards > 4 {
+        4
+    } else {
+        2
+    }
+}
+
+// every extra board gets one more guess; 6, 7, 9 and 13 guesses for 1, 2, 4 and 8 boards
+pub fn default_max_attempts(boards: usize) -> usize {
+    DEFAULT_ATTEMPTS + boards.saturating_sub(1)
+}
+
+/// Wordle game independent of any user interface
+///
+/// ```
+/// use wordl::Game;
+///
+/// let mut game = Game::new("pious");
+/// let feedback = game.guess("piano").unwrap();
+///
+/// assert_eq!(feedback.len(), 5);
+/// assert!(!game.is_over());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Game {
+    answer: String,
+    hard_mode: bool,
+    max_attempts: usize,
+    guesses: Vec<Vec<LetterStatus>>,
+    keyboard_hints: KeyboardHints,
+    valid_wordles: WordList,
+    valid_guesses: WordList,
+    dictionary: Dictionary,
+}
+
+impl Game {
+    /// new game with the given answer
+    pub fn new(answer: &str) -> Self {
+        Game {
+            answer: answer.to_lowercase(),
+            hard_mode: false,
+            max_attempts: DEFAULT_ATTEMPTS,
+            guesses: Vec::new(),
+            keyboard_hints: KeyboardHints::new(),
+            valid_wordles: WordList::Bundled(&wordle::dictionary::ANSWERS),
+            valid_guesses: WordList::Bundled(&wordle::dictionary::GUESSES),
+            dictionary: Dictionary::bundled(),
+        }
+    }
+
+    /// new game with a random answer from the bundled answer list
+    pub fn random() -> Self {
+        let answer = wordle::dictionary::ANSWERS
+            .choose(&mut rand::thread_rng())
+            .unwrap()
+            .to_string();
+
+        Game::new(&answer)
+    }
+
+    /// answers and allowed guesses; needed for answers that are not 5 letters long
+    pub fn with_word_lists(mut self, word_lists: WordLists) -> Self {
+        self.valid_wordles = word_lists.valid_wordles;
+        self.valid_guesses = word_lists.valid_guesses;
+        self.dictionary = word_lists.dictionary;
+        self
+    }
+
+    /// number of guesses allowed; defaults to [`DEFAULT_ATTEMPTS`]. Fails if it is not
+    /// [`MIN_ATTEMPTS`] to [`MAX_ATTEMPTS`]
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Result<Self, WordlError> {
+        if !(MIN_ATTEMPTS..=MAX_ATTEMPTS).contains(&max_attempts) {
+            return Err(WordlError::UnsupportedAttempts(max_attempts));
+        }
+
+        self.max_attempts = max_attempts;
+        Ok(self)
+    }
+
+    /// revealed hints must be used in subsequent guesses
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// scores the guess against the answer and returns feedback for every letter
+    pub fn guess(&mut self, word: &str) -> Result<Vec<LetterStatus>, WordlError> {
+        if self.is_over() {
+            return Err(WordlError::GameOver);
+        }
+
+        let word = word.to_lowercase();
+        wordle::utils::validate_guess(&word, self.word_length(), &self.dictionary)?;
+
+        if self.hard_mode {
+            wordle::utils::check_hard_mode(&self.guesses, &word)?;
+        }
+
+        let feedback = wordle::utils::check(self.answer.to_string(), word)?;
+        wordle::utils::update_keyboard_hints(&mut self.keyboard_hints, feedback.clone());
+        self.guesses.push(feedback.clone());
+
+        Ok(feedback)
+    }
+
+    /// next guess suggestions from the solver; see [`solver::hints`]
+    pub fn hints(&self, count: usize) -> Hints {
+        solver::hints(
+            &self.guesses,
+            &self.valid_wordles.as_strs(),
+            &self.valid_guesses.as_strs(),
+            count,
+        )
+    }
+
+    /// rating of the guesses made so far; see [`analysis::analyze`]
+    pub fn analysis(&self) -> Analysis {
+        analysis::analyze(
+            &self.guesses,
+            &self.valid_wordles.as_strs(),
+            &self.valid_guesses.as_strs(),
+        )
+    }
+
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.answer.chars().count()
+    }
+
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    pub fn hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    pub fn guesses(&self) -> &[Vec<LetterStatus>] {
+        &self.guesses
+    }
+
+    pub fn keyboard_hints(&self) -> &KeyboardHints {
+        &self.keyboard_hints
+    }
+
+    /// result of the game; `None` while the game is on
+    pub fn result(&self) -> Option<GameResult> {
+        let is_correct = self
+            .guesses
+            .last()
+            .is_some_and(|guess| wordle::utils::is_correct_guess(guess.clone()));
+
+        if is_correct {
+            Some(GameResult::CorrectGuess)
+        } else if self.guesses.len() >= self.max_attempts {
+            Some(GameResult::WrongGuess)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.result().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::game::*;
+    use crate::wordle::model::LetterState;
+
+    #[test]
+    fn test_game() {
+        let mut game = Game::new("pious");
+
+        let feedback = game.guess("piano").unwrap();
+        assert_eq!(feedback[0].status, LetterState::Correct);
+        assert_eq!(feedback[4].status, LetterState::Incorrect);
+        assert_eq!(game.result(), None);
+        assert_eq!(
+            *game.keyboard_hints().get(&'a').unwrap(),
+            LetterState::NotPresent
+        );
+
+        // invalid words are rejected without using up a guess
+        assert_eq!(
+            game.guess("xxxxx"),
+            Err(WordlError::NotInWordList("xxxxx".into()))
+        );
+        assert_eq!(
+            game.guess("pia"),
+            Err(WordlError::WrongLength {
+                expected: 5,
+                actual: 3
+            })
+        );
+        assert_eq!(game.guesses().len(), 1);
+
+        game.guess("PIOUS").unwrap();
+        assert_eq!(game.result(), Some(GameResult::CorrectGuess));
+        assert_eq!(game.guess("piano"), Err(WordlError::GameOver));
+    }
+
+    #[test]
+    fn test_game_lost() {
+        let mut game = Game::new("pious");
+
+        for _ in 0..DEFAULT_ATTEMPTS {
+            game.guess("crane").unwrap();
+        }
+
+        assert_eq!(game.result(), Some(GameResult::WrongGuess));
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn test_game_hard_mode() {
+        let mut game = Game::new("pious").with_hard_mode(true);
+
+        game.guess("piano").unwrap();
+        assert_eq!(
+            game.guess("crane").unwrap_err().to_string(),
+            "1st letter must be P"
+        );
+        assert!(game.guess("pilot").is_ok());
+    }
+
+    #[test]
+    fn test_game_word_length() {
+        let word_lists = WordLists::new(vec!["wolf".into(), "flow".into()], vec!["fowl".into()]);
+        let mut game = Game::new("wolf").with_word_lists(word_lists);
+        assert_eq!(game.word_length(), 4);
+
+        assert_eq!(game.guess("fowl").unwrap().len(), 4);
+        assert_eq!(
+            game.guess("pious").unwrap_err(),
+            WordlError::WrongLength {
+                expected: 4,
+                actual: 5
+            }
+        );
+        game.guess("wolf").unwrap();
+        assert_eq!(game.result(), Some(GameResult::CorrectGuess));
+    }
+
+    #[test]
+    fn test_game_max_attempts() {
+        let mut game = Game::new("pious").with_max_attempts(3).unwrap();
+        assert_eq!(game.max_attempts(), 3);
+
+        game.guess("crane").unwrap();
+        game.guess("crane").unwrap();
+        assert!(!game.is_over());
+        game.guess("crane").unwrap();
+        assert_eq!(game.result(), Some(GameResult::WrongGuess));
+        assert_eq!(game.guess("pious"), Err(WordlError::GameOver));
+
+        for attempts in [0, 14] {
+            assert_eq!(
+                Game::new("pious").with_max_attempts(attempts).unwrap_err(),
+                WordlError::UnsupportedAttempts(attempts)
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_max_attempts() {
+        let attempts: Vec<usize> = BOARD_COUNTS
+            .iter()
+            .map(|x| default_max_attempts(*x))
+            .collect();
+        assert_eq!(attempts, vec![6, 7, 9, 13]);
+    }
+}
@@ -1,3 +1,16 @@
+pub mod absurdle;
+pub mod analysis;
+pub mod animation;
+pub mod challenge;
+pub mod daily;
 pub mod data;
+pub mod dictionary;
+pub mod error;
+pub mod game;
 pub mod model;
+pub mod pattern;
+pub mod settings;
+pub mod solver;
+pub mod survival;
+pub mod timer;
 pub mod utils;
@@ -1,27 +1,142 @@
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
+use crate::stats::Stats;
 use crate::wordle;
+use crate::wordle::analysis::Analysis;
+use crate::wordle::animation::{AnimationSettings, Animations, Effect};
+use crate::wordle::error::WordlError;
+use crate::wordle::settings::Settings;
+use crate::wordle::solver::Hints;
+use crate::wordle::survival::Survival;
+use crate::wordle::timer::{Clock, Speedrun, Split};
 
 pub type KeyboardHints = HashMap<char, LetterState>;
 
+// horizontal offset (in columns) of the active row for every frame of the shake
+pub const SHAKE_OFFSETS: [i16; 8] = [-2, 2, -2, 2, -1, 1, -1, 0];
+
 #[derive(Debug, Default)]
 pub struct Model {
-    // main wordle word
-    pub wordle: String,
+    // one board per hidden wordle; multi board modes (dordle, quordle, octordle) have several
+    pub boards: Vec<Board>,
+    pub mode: GameMode,
+    // daily puzzle number (only for daily mode)
+    pub puzzle_number: Option<usize>,
+    // revealed hints must be used in subsequent guesses
+    pub hard_mode: bool,
+    // number of guesses allowed
+    pub max_attempts: usize,
+
+    // time taken by the current wordle
+    pub clock: Clock,
+    // beat the clock: game is lost once the time is up
+    pub time_limit: Option<Duration>,
+    // speedrun: consecutive wordles played against the clock
+    pub speedrun: Option<Speedrun>,
+    // survival: words are played one after the other from a shared attempt budget
+    pub survival: Option<Survival>,
 
     // data
-    pub valid_wordles: Vec<String>,
-    pub valid_guesses: Vec<String>,
+    pub valid_wordles: wordle::data::WordList,
+    pub valid_guesses: wordle::data::WordList,
+    // lookup of the allowed guesses
+    pub dictionary: wordle::dictionary::Dictionary,
+
+    // absurdle: wordles that are still possible; the board wordle is one of them
+    pub candidates: Vec<String>,
 
-    // user guesses
+    // user guess being typed
     pub active_guess: String,
-    pub guesses: Vec<Vec<LetterStatus>>,
+    // letter of the active guess being edited (clicked on); `None` when typing at the end
+    pub cursor: Option<usize>,
 
     pub running_state: RunningState,
+    // guess being revealed; checked once its tiles are flipped
+    pub reveal: Option<Vec<BoardGuess>>,
+    // grid cell animations
+    pub animations: Animations,
+    // theme, keyboard layout and key bindings
+    pub settings: Settings,
+    // message shown to the user (eg: copied to clipboard)
+    pub message: Option<String>,
+    // transient message shown over the grid (eg: rejected guess); hidden after a moment
+    pub toast: Option<String>,
+    // toasts shown so far; delayed messages act only on the toast they were sent for
+    pub toast_count: usize,
+    // frame of the active row shake; rejected guesses shake
+    pub shake_frame: Option<usize>,
+
+    // game statistics
+    pub stats: Stats,
+    pub show_stats: bool,
+
+    // share text of the finished game; only kept when it could not be copied to the clipboard
+    pub share_text: Option<String>,
+
+    // solver hints for the next guess
+    pub hints: Option<Hints>,
+    pub show_hints: bool,
+    // hints for the first guess; the same for every game with the word lists, so they are
+    // computed only once
+    pub opening_hints: Option<Hints>,
+
+    // post game analysis of the guesses
+    pub analysis: Option<Analysis>,
+    pub show_analysis: bool,
+}
+
+// hidden wordle with the guesses made against it
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Board {
+    pub wordle: String,
+    pub guesses: Vec<Vec<LetterStatus>>,
     pub keyboard_hints: KeyboardHints,
 }
 
+// feedback of a guess for one of the boards
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoardGuess {
+    // index of the board in `Model::boards`
+    pub board: usize,
+    pub guess: Vec<LetterStatus>,
+}
+
+// settings a game is started with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameOptions {
+    pub mode: GameMode,
+    pub hard_mode: bool,
+    pub word_length: usize,
+    pub max_attempts: usize,
+    // number of wordles guessed simultaneously
+    pub boards: usize,
+    // beat the clock mode; time allowed for a wordle
+    pub time_limit: Option<Duration>,
+    // speedrun mode; number of consecutive wordles
+    pub speedrun: Option<usize>,
+    // survival mode; `max_attempts` is the budget the run is started with
+    pub survival: bool,
+    // speed of the animations, and reduced motion
+    pub animation: AnimationSettings,
+    pub settings: Settings,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameMode {
+    // new random wordle for every game
+    #[default]
+    Random,
+    // one wordle per day derived from the local date
+    Daily,
+    // wordle is not picked up front; every guess keeps as many words possible as it can
+    Absurdle,
+    // wordle chosen by another player, shared as a challenge code
+    Challenge,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum GameResult {
     CorrectGuess,
@@ -42,19 +157,36 @@ pub enum Message {
     Listen(char),
     Erase,
     CalculateStart,
-    AnimateGuess(usize, Vec<LetterStatus>),
-    CalculateEnd(Vec<LetterStatus>),
+    ShowStats,
+    ToggleStats,
+    Share,
+    ToggleHints,
+    HintsReady(Hints),
+    ToggleAnalysis,
+    // letter of the active guess clicked on
+    Focus(usize),
+    // rejected guess; (toast, frame) of the active row shake
+    Shake(usize, usize),
+    // hides the toast, unless a newer one is shown
+    HideToast(usize),
+    AnalysisReady(Analysis),
+    // periodic tick; drives the game clock and the animations
+    Tick,
+    // animations are done at once
+    SkipAnimations,
+    // speedrun: next wordle of the run
+    NextWordle,
     Reset,
     Quit,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct LetterStatus {
     pub letter: char,
     pub status: LetterState,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum LetterState {
     #[default]
     Unknown,
@@ -63,34 +195,844 @@ pub enum LetterState {
     Incorrect,
 }
 
+impl Default for GameOptions {
+    fn default() -> Self {
+        GameOptions {
+            mode: GameMode::Random,
+            hard_mode: false,
+            word_length: wordle::utils::WORD_LENGTH,
+            max_attempts: wordle::game::DEFAULT_ATTEMPTS,
+            boards: 1,
+            time_limit: None,
+            speedrun: None,
+            survival: false,
+            animation: AnimationSettings::default(),
+            settings: Settings::default(),
+        }
+    }
+}
+
+impl Board {
+    pub fn new(wordle: &str) -> Self {
+        Board {
+            wordle: wordle.to_string(),
+            ..Board::default()
+        }
+    }
+
+    // solved boards do not take any more guesses
+    pub fn is_solved(&self) -> bool {
+        self.guesses
+            .last()
+            .is_some_and(|guess| wordle::utils::is_correct_guess(guess.clone()))
+    }
+}
+
 impl Model {
-    pub fn new() -> Self {
-        let valid_guesses = wordle::data::valid_guesses();
-        let valid_wordles = wordle::data::valid_wordles();
-        let wordle = valid_wordles
-            .choose(&mut rand::thread_rng())
-            .unwrap()
-            .to_string();
+    // fails if the options are not supported, or word lists for the word length are not available
+    pub fn new(options: GameOptions) -> Result<Self, WordlError> {
+        Model::with_word_lists(options, wordle::data::word_lists(options.word_length)?)
+    }
+
+    // game with the given word lists (eg: user's own lists); lists must have the word length of
+    // the options
+    pub fn with_word_lists(
+        options: GameOptions,
+        word_lists: wordle::data::WordLists,
+    ) -> Result<Self, WordlError> {
+        Model::with_stats(options, word_lists, Stats::load())
+    }
+
+    // game with the given stats; tests use empty stats instead of the user's stats files
+    fn with_stats(
+        options: GameOptions,
+        word_lists: wordle::data::WordLists,
+        stats: Stats,
+    ) -> Result<Self, WordlError> {
+        let max_attempts = options.max_attempts;
+        if !(wordle::game::MIN_ATTEMPTS..=wordle::game::MAX_ATTEMPTS).contains(&max_attempts) {
+            return Err(WordlError::UnsupportedAttempts(max_attempts));
+        }
+        // absurdle has only one (moving) wordle
+        let is_single_board = options.mode != GameMode::Absurdle || options.boards == 1;
+        if !wordle::game::BOARD_COUNTS.contains(&options.boards) || !is_single_board {
+            return Err(WordlError::UnsupportedBoards(options.boards));
+        }
+
+        let wordle::data::WordLists {
+            valid_wordles,
+            valid_guesses,
+            dictionary,
+        } = word_lists;
+        // every board needs a different wordle
+        if valid_wordles.len() < options.boards {
+            return Err(WordlError::UnsupportedBoards(options.boards));
+        }
 
         let default_model = Model::default();
 
-        Model {
-            wordle,
+        let mut model = Model {
+            boards: vec![Board::default(); options.boards],
+            mode: options.mode,
+            hard_mode: options.hard_mode,
+            max_attempts,
+            time_limit: options.time_limit,
+            speedrun: options.speedrun.map(Speedrun::new),
+            survival: options.survival.then(|| Survival::new(max_attempts)),
+            animations: Animations::new(options.animation),
+            settings: options.settings,
+            stats,
             valid_guesses,
             valid_wordles,
+            dictionary,
             ..default_model
-        }
+        };
+        model.pick_wordles();
+
+        Ok(model)
     }
 
     pub fn reset(&mut self) {
-        self.wordle = self
-            .valid_wordles
-            .choose(&mut rand::thread_rng())
-            .unwrap()
-            .to_string();
+        // daily puzzle cannot be rerolled; new game is possible only once the next puzzle is out
+        if self.mode == GameMode::Daily
+            && self.puzzle_number == Some(wordle::daily::todays_puzzle_number())
+        {
+            return;
+        }
+
+        // finished speedrun is started over
+        if let Some(speedrun) = &mut self.speedrun {
+            if speedrun.is_finished() {
+                *speedrun = Speedrun::new(speedrun.wordles);
+            }
+        }
+        // survival run goes on after a solved word with the unused attempts; otherwise it is
+        // started over
+        let used = self.attempts();
+        if let Some(survival) = &mut self.survival {
+            if self.running_state == RunningState::Over(GameResult::CorrectGuess) {
+                self.max_attempts = wordle::survival::next_budget(self.max_attempts, used);
+            } else {
+                self.max_attempts = survival.start_budget;
+                survival.words.clear();
+            }
+        }
+
+        // challenge is played once; next games are random
+        if self.mode == GameMode::Challenge {
+            self.mode = GameMode::Random;
+        }
+
+        self.pick_wordles();
+        self.clock = Clock::default();
         self.active_guess = "".into();
-        self.guesses.clear();
-        self.keyboard_hints.clear();
+        self.cursor = None;
+        self.message = None;
+        self.toast = None;
+        self.shake_frame = None;
+        self.reveal = None;
+        self.animations.clear();
+        self.show_stats = false;
+        self.hints = None;
+        self.show_hints = false;
+        self.analysis = None;
+        self.show_analysis = false;
         self.running_state = RunningState::Waiting;
     }
+
+    // number of letters in the wordle
+    pub fn word_length(&self) -> usize {
+        self.boards
+            .first()
+            .map_or(0, |board| board.wordle.chars().count())
+    }
+
+    // number of guesses made so far; unsolved boards take every guess
+    pub fn attempts(&self) -> usize {
+        self.boards
+            .iter()
+            .map(|board| board.guesses.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    // shows the toast; returns its number, so that it can be hidden later
+    pub fn show_toast(&mut self, text: &str) -> usize {
+        self.toast = Some(text.to_string());
+        self.toast_count += 1;
+
+        self.toast_count
+    }
+
+    // horizontal offset (in columns) of the active row; 0 unless it shakes
+    pub fn shake_offset(&self) -> i16 {
+        self.shake_frame
+            .and_then(|frame| SHAKE_OFFSETS.get(frame))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    // letter replaces the one at the cursor, or is added at the end of the active guess; returns
+    // the position of the letter (`None` if the guess is full)
+    pub fn type_letter(&mut self, letter: char) -> Option<usize> {
+        let letter = letter.to_ascii_lowercase();
+
+        match self.cursor {
+            Some(position) if position < self.active_guess.len() => {
+                self.active_guess
+                    .replace_range(position..=position, &letter.to_string());
+                // cursor moves on to the next letter; typing at the end once it is past the last
+                self.cursor = Some(position + 1).filter(|x| *x < self.active_guess.len());
+
+                Some(position)
+            }
+            _ => {
+                self.cursor = None;
+                if self.active_guess.len() >= self.word_length() {
+                    return None;
+                }
+                self.active_guess.push(letter);
+
+                Some(self.active_guess.len() - 1)
+            }
+        }
+    }
+
+    // typed letter pops on the active row of every board that is not solved yet
+    pub fn pop_letter(&mut self, column: usize, now: Instant) {
+        for (index, board) in self.boards.iter().enumerate() {
+            if !board.is_solved() {
+                self.animations.pop(index, board.guesses.len(), column, now);
+            }
+        }
+    }
+
+    // letter status is hidden until the tile is halfway through its flip
+    pub fn is_revealed(&self, board: usize, row: usize, column: usize, now: Instant) -> bool {
+        !matches!(
+            self.animations.cell(board, row, column, now),
+            Some((Effect::Flip, progress)) if progress < 0.5
+        )
+    }
+
+    // removes the letter at the cursor, or the last letter of the active guess
+    pub fn erase_letter(&mut self) {
+        match self.cursor {
+            Some(position) if position < self.active_guess.len() => {
+                self.active_guess.remove(position);
+                self.cursor = Some(position).filter(|x| *x < self.active_guess.len());
+            }
+            _ => {
+                self.active_guess.pop();
+                self.cursor = None;
+            }
+        }
+    }
+
+    // only typed letters can be edited; other cells go back to typing at the end
+    pub fn focus(&mut self, position: usize) {
+        self.cursor = Some(position).filter(|x| *x < self.active_guess.len());
+    }
+
+    pub fn wordles(&self) -> Vec<String> {
+        self.boards
+            .iter()
+            .map(|board| board.wordle.to_string())
+            .collect()
+    }
+
+    // absurdle: narrows down the candidates for the guess, and moves the wordle to one of the words
+    // left, so that the guess is scored against it like any other wordle
+    pub fn narrow_candidates(&mut self, guess: &str) -> Result<(), WordlError> {
+        if self.mode != GameMode::Absurdle {
+            return Ok(());
+        }
+
+        let candidates = wordle::absurdle::remaining_candidates(&self.candidates, guess)?;
+        if let (Some(board), Some(wordle)) = (self.boards.first_mut(), candidates.first()) {
+            board.wordle = wordle.to_string();
+        }
+        self.candidates = candidates;
+
+        Ok(())
+    }
+
+    // hard mode: guess has to use the revealed hints of every board that is not solved yet
+    pub fn check_hard_mode(&self, guess: &str) -> Result<(), WordlError> {
+        if !self.hard_mode {
+            return Ok(());
+        }
+
+        self.boards
+            .iter()
+            .filter(|board| !board.is_solved())
+            .try_for_each(|board| wordle::utils::check_hard_mode(&board.guesses, guess))
+    }
+
+    // scores the guess against every board that is not solved yet
+    pub fn check_guess(&self, guess: &str) -> Result<Vec<BoardGuess>, WordlError> {
+        self.boards
+            .iter()
+            .enumerate()
+            .filter(|(_, board)| !board.is_solved())
+            .map(|(index, board)| {
+                wordle::utils::check(board.wordle.to_string(), guess.to_string()).map(|guess| {
+                    BoardGuess {
+                        board: index,
+                        guess,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    // adds the scored guess to the boards and updates their keyboard hints
+    pub fn apply_guess(&mut self, board_guesses: &[BoardGuess]) {
+        for BoardGuess { board, guess } in board_guesses {
+            if let Some(board) = self.boards.get_mut(*board) {
+                wordle::utils::update_keyboard_hints(&mut board.keyboard_hints, guess.clone());
+                board.guesses.push(guess.clone());
+            }
+        }
+    }
+
+    // result of the game; `None` while the game is on
+    // game is won once every board is solved, and lost once the attempts run out
+    pub fn result(&self) -> Option<GameResult> {
+        if !self.boards.is_empty() && self.boards.iter().all(|board| board.is_solved()) {
+            Some(GameResult::CorrectGuess)
+        } else if self.attempts() >= self.max_attempts {
+            Some(GameResult::WrongGuess)
+        } else {
+            None
+        }
+    }
+
+    // ends the game; speedrun keeps the time taken for the wordle as a split
+    pub fn finish(&mut self, result: GameResult, now: Instant) {
+        self.clock.stop(now);
+
+        let split = Split {
+            wordle: self.wordles().join(","),
+            won: result == GameResult::CorrectGuess,
+            attempts: self.attempts(),
+            time: self.clock.elapsed(now),
+        };
+        if let Some(speedrun) = &mut self.speedrun {
+            speedrun.splits.push(split.clone());
+        }
+        if let (Some(survival), true) = (&mut self.survival, split.won) {
+            survival.words.push(split.wordle);
+        }
+
+        self.running_state = RunningState::Over(result);
+    }
+
+    // stats shown after a game; ignored once the game is reset (eg: new game started before the
+    // stats came up)
+    pub fn open_stats(&mut self) {
+        if matches!(self.running_state, RunningState::Over(_)) {
+            self.show_stats = true;
+        }
+    }
+
+    // survival: run goes on until a word is not solved
+    pub fn is_run_over(&self) -> bool {
+        self.survival.is_none() || self.running_state == RunningState::Over(GameResult::WrongGuess)
+    }
+
+    // timed games and runs cannot be resumed; clock and run are not saved
+    // challenge games are not saved either; resuming would give away the challenge wordle
+    pub fn is_resumable(&self) -> bool {
+        self.time_limit.is_none()
+            && self.speedrun.is_none()
+            && self.survival.is_none()
+            && self.mode != GameMode::Challenge
+    }
+
+    // starts the game with the wordle of a challenge code; only classic single board games can
+    // be challenges. Wordle must be in the answer list; hints and analysis look for the wordle
+    // among the answers
+    pub fn start_challenge(&mut self, wordle: &str) -> Result<(), WordlError> {
+        let is_classic = self.mode == GameMode::Random
+            && self.boards.len() == 1
+            && self.speedrun.is_none()
+            && self.survival.is_none();
+        if !is_classic {
+            return Err(WordlError::UnsupportedChallengeMode);
+        }
+        wordle::utils::validate_word(wordle, self.word_length())?;
+        if !self.valid_wordles.iter().any(|x| x == wordle) {
+            return Err(WordlError::ChallengeNotInWordList);
+        }
+
+        self.mode = GameMode::Challenge;
+        self.boards = vec![Board::new(wordle)];
+
+        Ok(())
+    }
+
+    // beat the clock: time left for the wordle
+    pub fn time_left(&self, now: Instant) -> Option<Duration> {
+        self.time_limit
+            .map(|limit| limit.saturating_sub(self.clock.elapsed(now)))
+    }
+
+    pub fn is_out_of_time(&self, now: Instant) -> bool {
+        self.time_left(now) == Some(Duration::ZERO)
+    }
+
+    // speedrun: time taken so far, including the wordle being played
+    pub fn speedrun_time(&self, now: Instant) -> Option<Duration> {
+        let current = match self.running_state {
+            // finished wordle is already one of the splits
+            RunningState::Over(_) => Duration::ZERO,
+            _ => self.clock.elapsed(now),
+        };
+
+        self.speedrun
+            .as_ref()
+            .map(|speedrun| speedrun.total_time() + current)
+    }
+
+    // picks wordles based on the game mode
+    fn pick_wordles(&mut self) {
+        let count = self.boards.len();
+        let wordles = match self.mode {
+            GameMode::Random => {
+                self.puzzle_number = None;
+                self.valid_wordles
+                    .as_strs()
+                    .choose_multiple(&mut rand::thread_rng(), count)
+                    .map(|x| x.to_string())
+                    .collect()
+            }
+            GameMode::Daily => {
+                let number = wordle::daily::todays_puzzle_number();
+                self.puzzle_number = Some(number);
+                wordle::daily::daily_wordles(&self.valid_wordles.as_strs(), number, count)
+            }
+            // chosen wordle is kept
+            GameMode::Challenge => self.wordles(),
+            // every word is possible until the first guess
+            GameMode::Absurdle => {
+                self.puzzle_number = None;
+                self.candidates = self.valid_wordles.to_vec();
+                self.candidates.iter().take(1).cloned().collect()
+            }
+        };
+
+        self.boards = wordles.iter().map(|wordle| Board::new(wordle)).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::model::*;
+
+    fn boards_model(wordles: &[&str], max_attempts: usize) -> Model {
+        Model {
+            boards: wordles.iter().map(|wordle| Board::new(wordle)).collect(),
+            max_attempts,
+            ..Model::default()
+        }
+    }
+
+    // model of the given options without the user's stats files
+    fn options_model(options: GameOptions) -> Result<Model, WordlError> {
+        let word_lists = wordle::data::word_lists(options.word_length)?;
+        Model::with_stats(options, word_lists, Stats::default())
+    }
+
+    fn play(model: &mut Model, guess: &str) {
+        let board_guesses = model.check_guess(guess).unwrap();
+        model.apply_guess(&board_guesses);
+    }
+
+    #[test]
+    fn test_boards() {
+        let mut model = boards_model(&["pious", "crane"], 7);
+        assert_eq!(model.word_length(), 5);
+        assert_eq!(model.wordles(), vec!["pious", "crane"]);
+
+        // guess is scored against every board
+        let board_guesses = model.check_guess("piano").unwrap();
+        assert_eq!(
+            board_guesses.iter().map(|x| x.board).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        model.apply_guess(&board_guesses);
+        assert_eq!(model.attempts(), 1);
+        assert_eq!(
+            model.boards[1].keyboard_hints.get(&'n'),
+            Some(&LetterState::Correct)
+        );
+
+        // solved board does not take any more guesses
+        play(&mut model, "pious");
+        assert!(model.boards[0].is_solved());
+        assert_eq!(model.check_guess("smile").unwrap()[0].board, 1);
+        assert_eq!(model.result(), None);
+
+        play(&mut model, "crane");
+        assert_eq!(model.attempts(), 3);
+        assert_eq!(model.boards[0].guesses.len(), 2);
+        assert_eq!(model.result(), Some(GameResult::CorrectGuess));
+    }
+
+    #[test]
+    fn test_boards_lost() {
+        let mut model = boards_model(&["pious", "crane"], 2);
+
+        play(&mut model, "pious");
+        assert_eq!(model.result(), None);
+        play(&mut model, "smile");
+        assert_eq!(model.result(), Some(GameResult::WrongGuess));
+    }
+
+    #[test]
+    fn test_boards_hard_mode() {
+        let mut model = Model {
+            hard_mode: true,
+            ..boards_model(&["pious", "crane"], 7)
+        };
+
+        // hints of every board have to be used
+        play(&mut model, "piano");
+        assert_eq!(
+            model.check_hard_mode("plumb").unwrap_err().to_string(),
+            "2nd letter must be I"
+        );
+        assert_eq!(
+            model.check_hard_mode("pinto").unwrap_err().to_string(),
+            "3rd letter must be A"
+        );
+        assert!(model.check_hard_mode("piano").is_ok());
+
+        // hints of a solved board no longer apply
+        play(&mut model, "pious");
+        assert!(model.boards[0].is_solved());
+        assert!(model.check_hard_mode("crane").is_ok());
+
+        // without hard mode, any guess goes
+        model.hard_mode = false;
+        assert!(model.check_hard_mode("smile").is_ok());
+    }
+
+    #[test]
+    fn test_open_stats() {
+        let mut model = boards_model(&["pious"], 6);
+        play(&mut model, "pious");
+        model.finish(GameResult::CorrectGuess, Instant::now());
+
+        // new game is started before the stats come up
+        model.reset();
+        model.open_stats();
+        assert!(!model.show_stats);
+
+        model.finish(GameResult::WrongGuess, Instant::now());
+        model.open_stats();
+        assert!(model.show_stats);
+    }
+
+    #[test]
+    fn test_absurdle() {
+        let candidates: Vec<String> = vec!["pious".into(), "crank".into(), "crane".into()];
+        let mut model = Model {
+            mode: GameMode::Absurdle,
+            candidates: candidates.clone(),
+            ..boards_model(&["pious"], 6)
+        };
+
+        // wordle moves to the largest group of words left
+        model.narrow_candidates("pilot").unwrap();
+        assert_eq!(model.candidates, vec!["crank", "crane"]);
+        assert_eq!(model.wordles(), vec!["crank"]);
+        play(&mut model, "pilot");
+
+        model.narrow_candidates("crank").unwrap();
+        play(&mut model, "crank");
+        assert_eq!(model.candidates, vec!["crane"]);
+        assert_eq!(model.result(), None);
+
+        model.narrow_candidates("crane").unwrap();
+        play(&mut model, "crane");
+        assert_eq!(model.result(), Some(GameResult::CorrectGuess));
+
+        // other modes keep the wordle
+        let mut model = Model {
+            candidates,
+            ..boards_model(&["pious"], 6)
+        };
+        model.narrow_candidates("pilot").unwrap();
+        assert_eq!(model.wordles(), vec!["pious"]);
+    }
+
+    #[test]
+    fn test_time_limit() {
+        let now = Instant::now();
+        let mut model = Model {
+            time_limit: Some(Duration::from_secs(60)),
+            ..boards_model(&["pious"], 6)
+        };
+        // clock starts with the first letter
+        assert_eq!(model.time_left(now), Some(Duration::from_secs(60)));
+        assert!(!model.is_out_of_time(now + Duration::from_secs(90)));
+
+        model.clock.start(now);
+        assert_eq!(
+            model.time_left(now + Duration::from_secs(45)),
+            Some(Duration::from_secs(15))
+        );
+        assert!(model.is_out_of_time(now + Duration::from_secs(90)));
+
+        // no time limit by default
+        assert_eq!(boards_model(&["pious"], 6).time_left(now), None);
+    }
+
+    #[test]
+    fn test_speedrun() {
+        let now = Instant::now();
+        let mut model = Model {
+            speedrun: Some(Speedrun::new(2)),
+            ..boards_model(&["pious"], 6)
+        };
+        model.valid_wordles = vec!["crane".to_string()].into();
+
+        model.clock.start(now);
+        play(&mut model, "pious");
+        model.finish(GameResult::CorrectGuess, now + Duration::from_secs(20));
+        assert_eq!(
+            model.speedrun_time(now + Duration::from_secs(25)),
+            Some(Duration::from_secs(20))
+        );
+
+        // next wordle of the run
+        model.reset();
+        assert_eq!(model.running_state, RunningState::Waiting);
+        model.clock.start(now + Duration::from_secs(30));
+        assert_eq!(
+            model.speedrun_time(now + Duration::from_secs(40)),
+            Some(Duration::from_secs(30))
+        );
+        model.finish(GameResult::WrongGuess, now + Duration::from_secs(50));
+
+        let speedrun = model.speedrun.clone().unwrap();
+        assert!(speedrun.is_finished());
+        assert_eq!(speedrun.solved(), 1);
+        assert_eq!(speedrun.splits[0].attempts, 1);
+        assert_eq!(speedrun.splits[1].wordle, "crane");
+        assert_eq!(speedrun.total_time(), Duration::from_secs(40));
+
+        // finished run is started over
+        model.reset();
+        assert_eq!(model.speedrun, Some(Speedrun::new(2)));
+    }
+
+    #[test]
+    fn test_survival() {
+        let mut model = Model {
+            survival: Some(Survival::new(6)),
+            valid_wordles: vec!["crane".to_string()].into(),
+            ..boards_model(&["pious"], 6)
+        };
+        let now = Instant::now();
+
+        // solved in 2; unused attempts roll over to the next word
+        play(&mut model, "smile");
+        play(&mut model, "pious");
+        model.finish(model.result().unwrap(), now);
+        assert!(!model.is_run_over());
+        model.reset();
+        assert_eq!(model.max_attempts, 8);
+        assert_eq!(model.attempts(), 0);
+        assert_eq!(model.survival.as_ref().unwrap().run_length(), 1);
+
+        // run is over once the budget is exhausted
+        for _ in 0..8 {
+            play(&mut model, "smile");
+        }
+        model.finish(model.result().unwrap(), now);
+        assert!(model.is_run_over());
+        assert_eq!(model.survival.as_ref().unwrap().run_length(), 1);
+
+        // new run starts with the initial budget
+        model.reset();
+        assert_eq!(model.max_attempts, 6);
+        assert_eq!(model.survival, Some(Survival::new(6)));
+        assert!(!model.is_resumable());
+    }
+
+    #[test]
+    fn test_challenge() {
+        let mut model = Model {
+            valid_wordles: vec!["crane".to_string(), "plumb".into()].into(),
+            dictionary: wordle::dictionary::Dictionary::bundled(),
+            ..boards_model(&["pious"], 6)
+        };
+        assert_eq!(
+            model.start_challenge("plum"),
+            Err(WordlError::WrongLength {
+                expected: 5,
+                actual: 4
+            })
+        );
+        // eg: code created with a word list pack
+        assert_eq!(
+            model.start_challenge("qqqqq"),
+            Err(WordlError::ChallengeNotInWordList)
+        );
+        // allowed guess, but not an answer
+        assert_eq!(
+            model.start_challenge("pious"),
+            Err(WordlError::ChallengeNotInWordList)
+        );
+
+        model.start_challenge("plumb").unwrap();
+        assert_eq!(model.mode, GameMode::Challenge);
+        assert_eq!(model.wordles(), vec!["plumb"]);
+        assert!(!model.is_resumable());
+
+        // next game after the challenge is random
+        model.valid_wordles = vec!["crane".to_string()].into();
+        model.running_state = RunningState::Over(GameResult::CorrectGuess);
+        model.reset();
+        assert_eq!(model.mode, GameMode::Random);
+        assert_eq!(model.wordles(), vec!["crane"]);
+
+        let mut model = Model {
+            survival: Some(Survival::new(6)),
+            ..boards_model(&["pious"], 6)
+        };
+        assert_eq!(
+            model.start_challenge("plumb"),
+            Err(WordlError::UnsupportedChallengeMode)
+        );
+        let mut model = boards_model(&["pious", "crane"], 7);
+        assert_eq!(
+            model.start_challenge("plumb"),
+            Err(WordlError::UnsupportedChallengeMode)
+        );
+    }
+
+    #[test]
+    fn test_edit_active_guess() {
+        let mut model = boards_model(&["pious"], 6);
+
+        for letter in "PLANTS".chars() {
+            model.type_letter(letter);
+        }
+        assert_eq!(model.active_guess, "plant");
+        assert_eq!(model.type_letter('s'), None);
+
+        // clicked letter is replaced, and the cursor moves on
+        model.focus(1);
+        assert_eq!(model.type_letter('i'), Some(1));
+        assert_eq!(model.active_guess, "piant");
+        assert_eq!(model.cursor, Some(2));
+        model.focus(4);
+        model.type_letter('o');
+        assert_eq!(model.active_guess, "piano");
+        assert_eq!(model.cursor, None);
+
+        // clicked letter is erased
+        model.focus(2);
+        model.erase_letter();
+        assert_eq!(model.active_guess, "pino");
+        assert_eq!(model.cursor, Some(2));
+        model.focus(3);
+        model.erase_letter();
+        assert_eq!(model.active_guess, "pin");
+        assert_eq!(model.cursor, None);
+
+        // empty cells cannot be focused
+        model.focus(4);
+        assert_eq!(model.cursor, None);
+        model.erase_letter();
+        model.type_letter('e');
+        assert_eq!(model.active_guess, "pie");
+    }
+
+    #[test]
+    fn test_pop_letter() {
+        let mut model = boards_model(&["pious", "crane", "plumb"], 8);
+        play(&mut model, "pious");
+
+        // typed letter pops on the boards that are not solved
+        let now = Instant::now();
+        let column = model.type_letter('c').unwrap();
+        model.pop_letter(column, now);
+        assert_eq!(model.animations.cell(0, 1, 0, now), None);
+        assert_eq!(
+            model.animations.cell(1, 1, 0, now),
+            Some((Effect::Pop, 0.0))
+        );
+        assert_eq!(
+            model.animations.cell(2, 1, 0, now),
+            Some((Effect::Pop, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_toast_and_shake() {
+        let mut model = boards_model(&["pious"], 6);
+        assert_eq!(model.shake_offset(), 0);
+
+        let first = model.show_toast("Not in word list");
+        let second = model.show_toast("Not in word list");
+        assert_eq!(second, first + 1);
+        assert_eq!(model.toast_count, second);
+
+        model.shake_frame = Some(0);
+        assert_eq!(model.shake_offset(), SHAKE_OFFSETS[0]);
+        model.shake_frame = Some(SHAKE_OFFSETS.len());
+        assert_eq!(model.shake_offset(), 0);
+
+        model.reset();
+        assert_eq!(model.toast, None);
+        assert_eq!(model.shake_frame, None);
+    }
+
+    #[test]
+    fn test_new_options() {
+        let options = GameOptions {
+            boards: 3,
+            ..GameOptions::default()
+        };
+        assert_eq!(
+            options_model(options).unwrap_err(),
+            WordlError::UnsupportedBoards(3)
+        );
+
+        let options = GameOptions {
+            mode: GameMode::Absurdle,
+            boards: 2,
+            ..GameOptions::default()
+        };
+        assert_eq!(
+            options_model(options).unwrap_err(),
+            WordlError::UnsupportedBoards(2)
+        );
+
+        let options = GameOptions {
+            max_attempts: 14,
+            ..GameOptions::default()
+        };
+        assert_eq!(
+            options_model(options).unwrap_err(),
+            WordlError::UnsupportedAttempts(14)
+        );
+
+        // every board gets a different wordle
+        let options = GameOptions {
+            boards: 8,
+            max_attempts: 13,
+            ..GameOptions::default()
+        };
+        let model = options_model(options).unwrap();
+        let mut wordles = model.wordles();
+        wordles.sort();
+        wordles.dedup();
+        assert_eq!(wordles.len(), 8);
+    }
 }
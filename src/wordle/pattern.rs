@@ -0,0 +1,270 @@
+use super::model::{LetterState, LetterStatus};
+use super::utils::MAX_WORD_LENGTH;
+
+/// Feedback of a guess as a base 3 number; one digit per letter (0 - not present, 1 - incorrect,
+/// 2 - correct), first letter being the least significant digit. 5 letter patterns are 0..243;
+/// 8 letter words need up to 3^8, hence `u16`
+pub type Pattern = u16;
+
+// marks guess and answer pairs that cannot be scored (different lengths) in the matrix
+const NO_PATTERN: Pattern = Pattern::MAX;
+
+/// number of possible patterns for the word length
+pub fn pattern_count(length: usize) -> usize {
+    3usize.pow(length.min(MAX_WORD_LENGTH) as u32)
+}
+
+/// pattern of a guess with every letter correct
+pub fn solved_pattern(length: usize) -> Pattern {
+    (pattern_count(length) - 1) as Pattern
+}
+
+/// allocation free version of `utils::check`; duplicate letters are colored exactly like
+/// `utils::check` does. `None` if the words have different lengths (or are too long)
+pub fn score(wordle: &[u8], guess: &[u8]) -> Option<Pattern> {
+    let length = wordle.len();
+    if length != guess.len() || length > MAX_WORD_LENGTH {
+        return None;
+    }
+
+    let mut pattern = 0;
+    for position in (0..length).rev() {
+        let letter = guess[position];
+        let status = if wordle[position] == letter {
+            2
+        } else if !wordle.contains(&letter) {
+            0
+        } else {
+            // letter positions in the wordle and guess
+            let mut wordle_indices = [0; MAX_WORD_LENGTH];
+            let mut guess_indices = [0; MAX_WORD_LENGTH];
+            let mut coloring_chances = 0;
+            let mut guess_count = 0;
+            for index in 0..length {
+                if wordle[index] == letter {
+                    wordle_indices[coloring_chances] = index;
+                    coloring_chances += 1;
+                }
+                if guess[index] == letter {
+                    guess_indices[guess_count] = index;
+                    guess_count += 1;
+                }
+            }
+            let wordle_indices = &wordle_indices[..coloring_chances];
+            let guess_indices = &guess_indices[..guess_count];
+
+            let matches = wordle_indices
+                .iter()
+                .filter(|i| guess_indices.contains(i))
+                .count();
+            let future_start = guess_indices.partition_point(|&i| i == position);
+            let future_chances = guess_count - future_start;
+
+            let should_leave = matches == coloring_chances || future_chances >= coloring_chances;
+            if should_leave {
+                0
+            } else {
+                1
+            }
+        };
+
+        pattern = pattern * 3 + status;
+    }
+
+    Some(pattern)
+}
+
+/// pattern of the feedback returned by `utils::check`
+pub fn encode(feedback: &[LetterStatus]) -> Pattern {
+    feedback.iter().rev().fold(0, |pattern, x| {
+        pattern * 3
+            + match x.status {
+                LetterState::Correct => 2,
+                LetterState::Incorrect => 1,
+                LetterState::NotPresent | LetterState::Unknown => 0,
+            }
+    })
+}
+
+/// feedback for the guess letters from the pattern
+pub fn decode(pattern: Pattern, guess: &str) -> Vec<LetterStatus> {
+    let mut pattern = pattern;
+
+    guess
+        .chars()
+        .map(|letter| {
+            let status = match pattern % 3 {
+                2 => LetterState::Correct,
+                1 => LetterState::Incorrect,
+                _ => LetterState::NotPresent,
+            };
+            pattern /= 3;
+
+            LetterStatus { letter, status }
+        })
+        .collect()
+}
+
+/// Patterns of every guess against every answer, computed up front
+///
+/// Scoring all the guesses against the remaining answers is the bulk of the solver work. With the
+/// matrix, it is a table lookup. Bundled lists take about 70MB, so the matrix is opt in.
+///
+/// ```
+/// use wordl::wordle::pattern::{self, PatternMatrix};
+///
+/// let answers = vec!["pious".to_string(), "crane".to_string()];
+/// let matrix = PatternMatrix::new(&answers, &answers);
+///
+/// assert_eq!(matrix.pattern(0, 0), Some(pattern::solved_pattern(5)));
+/// assert_eq!(matrix.entropy(1, &[0, 1]), 1.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PatternMatrix {
+    answer_count: usize,
+    // patterns of the answer length
+    pattern_count: usize,
+    // one row per guess, one column per answer
+    patterns: Vec<Pattern>,
+}
+
+impl PatternMatrix {
+    pub fn new(guesses: &[String], answers: &[String]) -> Self {
+        let patterns = guesses
+            .iter()
+            .flat_map(|guess| {
+                answers.iter().map(move |answer| {
+                    score(answer.as_bytes(), guess.as_bytes()).unwrap_or(NO_PATTERN)
+                })
+            })
+            .collect();
+
+        PatternMatrix {
+            answer_count: answers.len(),
+            pattern_count: answers.first().map_or(0, |x| pattern_count(x.len())),
+            patterns,
+        }
+    }
+
+    /// pattern of the guess against the answer; indices are positions in the lists the matrix
+    /// was built with
+    pub fn pattern(&self, guess: usize, answer: usize) -> Option<Pattern> {
+        self.row(guess)
+            .get(answer)
+            .copied()
+            .filter(|x| *x != NO_PATTERN)
+    }
+
+    /// expected information (in bits) of the guess over the candidate answers
+    pub fn entropy(&self, guess: usize, candidates: &[usize]) -> f64 {
+        let row = self.row(guess);
+        let mut buckets = vec![0usize; self.pattern_count];
+
+        for &candidate in candidates {
+            if let Some(count) = row
+                .get(candidate)
+                .and_then(|&pattern| buckets.get_mut(usize::from(pattern)))
+            {
+                *count += 1;
+            }
+        }
+
+        bucket_entropy(&buckets)
+    }
+
+    fn row(&self, guess: usize) -> &[Pattern] {
+        let start = guess * self.answer_count;
+        self.patterns
+            .get(start..start + self.answer_count)
+            .unwrap_or_default()
+    }
+}
+
+// entropy (in bits) of the candidates split into buckets
+pub(crate) fn bucket_entropy(buckets: &[usize]) -> f64 {
+    let total = buckets.iter().sum::<usize>() as f64;
+
+    buckets
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let probability = count as f64 / total;
+            probability * (1.0 / probability).log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::data::{valid_guesses, valid_wordles};
+    use crate::wordle::pattern::*;
+    use crate::wordle::utils::check;
+
+    #[test]
+    fn test_score_matches_check() {
+        let wordles = valid_wordles();
+        let guesses = valid_guesses();
+
+        for guess in guesses.iter().step_by(97).chain(wordles.iter().step_by(13)) {
+            for wordle in &wordles {
+                let expected = encode(&check(wordle.to_string(), guess.to_string()).unwrap());
+                assert_eq!(
+                    score(wordle.as_bytes(), guess.as_bytes()),
+                    Some(expected),
+                    "{} {}",
+                    wordle,
+                    guess
+                );
+            }
+        }
+
+        // other word lengths
+        let words = [
+            "wolf", "flow", "loll", "fool", "mountain", "mounting", "notation",
+        ];
+        for wordle in words {
+            for guess in words.iter().filter(|x| x.len() == wordle.len()) {
+                let expected = encode(&check(wordle.to_string(), guess.to_string()).unwrap());
+                assert_eq!(score(wordle.as_bytes(), guess.as_bytes()), Some(expected));
+            }
+        }
+        assert_eq!(score(b"wolf", b"pious"), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let feedback = check("abbey".into(), "about".into()).unwrap();
+        let pattern = encode(&feedback);
+        assert_eq!(pattern, 2 + 2 * 3);
+        assert_eq!(decode(pattern, "about"), feedback);
+
+        assert_eq!(pattern_count(5), 243);
+        assert_eq!(solved_pattern(5), 242);
+        assert_eq!(
+            encode(&check("pious".into(), "pious".into()).unwrap()),
+            solved_pattern(5)
+        );
+    }
+
+    #[test]
+    fn test_pattern_matrix() {
+        let answers: Vec<String> = vec!["pious".into(), "pilot".into(), "crane".into()];
+        let guesses: Vec<String> = vec!["piano".into(), "moist".into(), "wolf".into()];
+        let matrix = PatternMatrix::new(&guesses, &answers);
+
+        for (guess_index, guess) in guesses.iter().enumerate() {
+            for (answer_index, answer) in answers.iter().enumerate() {
+                assert_eq!(
+                    matrix.pattern(guess_index, answer_index),
+                    score(answer.as_bytes(), guess.as_bytes())
+                );
+            }
+        }
+        assert_eq!(matrix.pattern(3, 0), None);
+
+        // piano gives the same feedback for pious and pilot
+        assert_eq!(matrix.entropy(0, &[0, 1]), 0.0);
+        assert_eq!(matrix.entropy(1, &[0, 1]), 1.0);
+        assert_eq!(matrix.entropy(2, &[0, 1, 2]), 0.0);
+    }
+}
@@ -0,0 +1,263 @@
+use serde::Deserialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::wordle::error::WordlError;
+use crate::wordle::model::Message;
+
+/// Look and controls of the game; plain data, mapped to colors and keys by the terminal interface
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Settings {
+    pub theme: Theme,
+    pub keyboard_layout: KeyboardLayout,
+    pub key_bindings: KeyBindings,
+}
+
+/// Tile and key colors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    /// green and yellow tiles
+    #[default]
+    Dark,
+    /// orange and blue tiles; easier to tell apart with color blindness
+    HighContrast,
+}
+
+/// Letter arrangement of the on-screen keyboard
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Qwertz,
+    Azerty,
+    Dvorak,
+    Colemak,
+}
+
+impl KeyboardLayout {
+    /// letter rows of the keyboard, top to bottom
+    pub fn rows(&self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Qwertz => ["qwertzuiop", "asdfghjkl", "yxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            KeyboardLayout::Dvorak => ["pyfgcrl", "aoeuidhtns", "qjkxbmwvz"],
+            KeyboardLayout::Colemak => ["qwfpgjluy", "arstdhneio", "zxcvbkm"],
+        }
+    }
+}
+
+/// Key that can be bound to an action
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyName {
+    /// lowercase character
+    Char(char),
+    Esc,
+    Tab,
+    /// function key (1 to 12)
+    F(u8),
+}
+
+/// Key, pressed with or without the control key
+///
+/// ```
+/// use wordl::wordle::settings::{KeyBinding, KeyName};
+///
+/// let binding: KeyBinding = "ctrl-n".parse().unwrap();
+/// assert_eq!(binding, KeyBinding { key: KeyName::Char('n'), ctrl: true });
+/// assert_eq!(binding.to_string(), "Ctrl-N");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct KeyBinding {
+    pub key: KeyName,
+    pub ctrl: bool,
+}
+
+/// Keys of the game actions; letters, enter and backspace always type the guess
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct KeyBindings {
+    pub new_game: KeyBinding,
+    pub stats: KeyBinding,
+    pub share: KeyBinding,
+    pub analysis: KeyBinding,
+    pub hints: KeyBinding,
+    pub quit: KeyBinding,
+}
+
+impl KeyBinding {
+    const fn ctrl(letter: char) -> Self {
+        KeyBinding {
+            key: KeyName::Char(letter),
+            ctrl: true,
+        }
+    }
+
+    const fn key(key: KeyName) -> Self {
+        KeyBinding { key, ctrl: false }
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            new_game: KeyBinding::ctrl('n'),
+            stats: KeyBinding::ctrl('s'),
+            share: KeyBinding::ctrl('y'),
+            analysis: KeyBinding::ctrl('a'),
+            hints: KeyBinding::key(KeyName::Char('?')),
+            quit: KeyBinding::key(KeyName::Esc),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// message of the action bound to the key
+    pub fn message(&self, binding: KeyBinding) -> Option<Message> {
+        self.actions()
+            .into_iter()
+            .find(|(action, _)| *action == binding)
+            .map(|(_, message)| message)
+    }
+
+    /// fails if a key is bound to more than one action
+    pub fn validate(&self) -> Result<(), WordlError> {
+        let actions = self.actions();
+        for (index, (binding, _)) in actions.iter().enumerate() {
+            if actions[..index].iter().any(|(other, _)| other == binding) {
+                return Err(WordlError::DuplicateKeyBinding(binding.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn actions(&self) -> [(KeyBinding, Message); 6] {
+        [
+            (self.new_game, Message::Reset),
+            (self.stats, Message::ToggleStats),
+            (self.share, Message::Share),
+            (self.analysis, Message::ToggleAnalysis),
+            (self.hints, Message::ToggleHints),
+            (self.quit, Message::Quit),
+        ]
+    }
+}
+
+// eg: `?`, `ctrl-n`, `esc`, `f1`
+impl FromStr for KeyBinding {
+    type Err = WordlError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || WordlError::InvalidKeyBinding(text.to_string());
+        let reserved = || WordlError::ReservedKeyBinding(text.to_string());
+
+        let lowercase = text.trim().to_lowercase();
+        let (ctrl, name) = match lowercase.strip_prefix("ctrl-") {
+            Some(name) => (true, name),
+            None => (false, lowercase.as_str()),
+        };
+
+        let key = match name {
+            "esc" => KeyName::Esc,
+            "tab" => KeyName::Tab,
+            "enter" | "backspace" | "delete" => return Err(reserved()),
+            _ => match name.strip_prefix('f').map(str::parse::<u8>) {
+                Some(Ok(number)) if (1..=12).contains(&number) => KeyName::F(number),
+                Some(Ok(_)) => return Err(invalid()),
+                _ => {
+                    let mut chars = name.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(letter), None) if !letter.is_whitespace() => KeyName::Char(letter),
+                        _ => return Err(invalid()),
+                    }
+                }
+            },
+        };
+
+        match key {
+            // letters type the guess
+            KeyName::Char(letter) if letter.is_ascii_alphabetic() && !ctrl => Err(reserved()),
+            KeyName::Char('c') if ctrl => Err(reserved()),
+            _ => Ok(KeyBinding { key, ctrl }),
+        }
+    }
+}
+
+impl TryFrom<String> for KeyBinding {
+    type Error = WordlError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+// as shown in the help texts; eg: `Ctrl-N`
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl-")?;
+        }
+
+        match self.key {
+            KeyName::Char(letter) => write!(f, "{}", letter.to_ascii_uppercase()),
+            KeyName::Esc => write!(f, "Esc"),
+            KeyName::Tab => write!(f, "Tab"),
+            KeyName::F(number) => write!(f, "F{}", number),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::settings::*;
+
+    #[test]
+    fn test_parse_key_binding() {
+        assert_eq!("?".parse(), Ok(KeyBinding::key(KeyName::Char('?'))));
+        assert_eq!("Ctrl-X".parse(), Ok(KeyBinding::ctrl('x')));
+        assert_eq!("esc".parse(), Ok(KeyBinding::key(KeyName::Esc)));
+        assert_eq!("F5".parse(), Ok(KeyBinding::key(KeyName::F(5))));
+        assert_eq!(
+            "ctrl-tab".parse(),
+            Ok(KeyBinding {
+                key: KeyName::Tab,
+                ctrl: true
+            })
+        );
+
+        for text in ["", "ctrl-", "f13", "space bar"] {
+            assert_eq!(
+                text.parse::<KeyBinding>(),
+                Err(WordlError::InvalidKeyBinding(text.into()))
+            );
+        }
+        for text in ["n", "enter", "ctrl-c"] {
+            assert_eq!(
+                text.parse::<KeyBinding>(),
+                Err(WordlError::ReservedKeyBinding(text.into()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_key_bindings() {
+        let mut key_bindings = KeyBindings::default();
+        assert_eq!(
+            key_bindings.message(KeyBinding::ctrl('n')),
+            Some(Message::Reset)
+        );
+        assert_eq!(key_bindings.message(KeyBinding::ctrl('x')), None);
+        assert_eq!(key_bindings.validate(), Ok(()));
+
+        key_bindings.quit = KeyBinding::ctrl('s');
+        assert_eq!(
+            key_bindings.validate(),
+            Err(WordlError::DuplicateKeyBinding("Ctrl-S".into()))
+        );
+        assert_eq!(KeyBinding::key(KeyName::F(1)).to_string(), "F1");
+    }
+}
@@ -0,0 +1,379 @@
+use std::collections::{HashMap, HashSet};
+
+use super::error::WordlError;
+use super::model::LetterStatus;
+use super::pattern::{self, PatternMatrix};
+use super::utils;
+
+/// Suggested guess with its expected information (in bits)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    pub word: String,
+    pub entropy: f64,
+    /// guess is one of the remaining candidates (could be the wordle)
+    pub is_candidate: bool,
+}
+
+/// Solver output for the current game state
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hints {
+    /// number of guesses the hints were computed for
+    pub attempt: usize,
+    /// wordles consistent with all the feedback so far
+    pub candidates: Vec<String>,
+    /// best guesses; highest expected information first
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// Suggests the next guesses for the given feedback history
+///
+/// ```
+/// use wordl::{solver, Game};
+///
+/// let mut game = Game::new("ulcer");
+/// game.guess("crane").unwrap();
+///
+/// let hints = solver::hints(game.guesses(), &wordl::valid_wordles(), &wordl::valid_guesses(), 3);
+///
+/// assert!(hints.candidates.contains(&"ulcer".to_string()));
+/// assert_eq!(hints.suggestions.len(), 3);
+/// ```
+pub fn hints<W: AsRef<str>>(
+    guesses: &[Vec<LetterStatus>],
+    valid_wordles: &[W],
+    valid_guesses: &[W],
+    count: usize,
+) -> Hints {
+    let candidates = candidates(guesses, valid_wordles);
+
+    // every word can be guessed; wordles are ranked along with the other guesses
+    let all_guesses: Vec<String> = valid_wordles
+        .iter()
+        .chain(valid_guesses.iter())
+        .map(|x| x.as_ref().to_string())
+        .collect();
+    let mut suggestions = rank_guesses(&candidates, &all_guesses);
+    suggestions.truncate(count);
+
+    Hints {
+        attempt: guesses.len(),
+        candidates,
+        suggestions,
+    }
+}
+
+/// Strategy used by the [`Solver`] to pick the next guess
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// guess with the highest expected information
+    #[default]
+    Entropy,
+    /// first remaining candidate; baseline to compare other strategies against
+    FirstCandidate,
+}
+
+/// Plays wordle on its own with the given strategy
+///
+/// Next guess depends only on the feedback so far, so guesses are cached for every feedback
+/// history. This makes solving many wordles in a row (eg: benchmarking) a lot faster.
+///
+/// ```
+/// use wordl::solver::{Solver, Strategy};
+///
+/// let mut solver = Solver::new(
+///     Strategy::FirstCandidate,
+///     wordl::valid_wordles(),
+///     wordl::valid_guesses(),
+/// );
+/// let guesses = solver.solve("ulcer", 10).unwrap();
+///
+/// assert_eq!(guesses.last().unwrap()[0].letter, 'u');
+/// ```
+#[derive(Debug)]
+pub struct Solver {
+    strategy: Strategy,
+    valid_wordles: Vec<String>,
+    // wordles come first; wordle and guess positions are the same in the pattern matrix
+    all_guesses: Vec<String>,
+    cache: HashMap<Vec<Vec<LetterStatus>>, String>,
+    matrix: Option<PatternMatrix>,
+}
+
+impl Solver {
+    pub fn new(strategy: Strategy, valid_wordles: Vec<String>, valid_guesses: Vec<String>) -> Self {
+        let all_guesses = valid_wordles
+            .iter()
+            .chain(valid_guesses.iter())
+            .cloned()
+            .collect();
+
+        Solver {
+            strategy,
+            valid_wordles,
+            all_guesses,
+            cache: HashMap::new(),
+            matrix: None,
+        }
+    }
+
+    /// precomputes the pattern of every guess against every wordle; guesses are picked the same
+    /// way, only faster. Worth it when solving many wordles; see [`PatternMatrix`]
+    pub fn with_pattern_matrix(mut self) -> Self {
+        self.matrix = Some(PatternMatrix::new(&self.all_guesses, &self.valid_wordles));
+        self
+    }
+
+    /// next guess for the feedback history; `None` if no wordle fits the feedback
+    pub fn next_guess(&mut self, guesses: &[Vec<LetterStatus>]) -> Option<String> {
+        if let Some(guess) = self.cache.get(guesses) {
+            return Some(guess.to_string());
+        }
+
+        let candidates = candidate_indices(guesses, &self.valid_wordles);
+        let guess = match (self.strategy, &self.matrix) {
+            (Strategy::Entropy, Some(matrix)) => self.best_guess(matrix, &candidates),
+            (Strategy::Entropy, None) => {
+                let candidates: Vec<String> = candidates
+                    .iter()
+                    .map(|&index| self.valid_wordles[index].to_string())
+                    .collect();
+                rank_guesses(&candidates, &self.all_guesses)
+                    .into_iter()
+                    .next()
+                    .map(|x| x.word)
+            }
+            (Strategy::FirstCandidate, _) => candidates
+                .first()
+                .map(|&index| self.valid_wordles[index].to_string()),
+        }?;
+
+        self.cache.insert(guesses.to_vec(), guess.to_string());
+
+        Some(guess)
+    }
+
+    // top guess of `rank_guesses`, with the entropies looked up in the pattern matrix
+    fn best_guess(&self, matrix: &PatternMatrix, candidates: &[usize]) -> Option<String> {
+        let candidate_words: HashSet<&str> = candidates
+            .iter()
+            .map(|&index| self.valid_wordles[index].as_str())
+            .collect();
+        // with one or two candidates left, guessing a candidate is always the best bet
+        let guesses: Vec<usize> = if candidates.len() <= 2 {
+            candidates.to_vec()
+        } else {
+            (0..self.all_guesses.len()).collect()
+        };
+
+        guesses
+            .into_iter()
+            .map(|index| {
+                let word = self.all_guesses[index].as_str();
+                (
+                    matrix.entropy(index, candidates),
+                    candidate_words.contains(word),
+                    word,
+                )
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(b.2.cmp(a.2)))
+            .map(|(_, _, word)| word.to_string())
+    }
+
+    /// solves the wordle using `utils::check` as the feedback oracle; returns feedback for every
+    /// guess made. Solver gives up after `max_guesses`.
+    pub fn solve(
+        &mut self,
+        answer: &str,
+        max_guesses: usize,
+    ) -> Result<Vec<Vec<LetterStatus>>, WordlError> {
+        let mut guesses: Vec<Vec<LetterStatus>> = Vec::new();
+
+        while guesses.len() < max_guesses {
+            let Some(guess) = self.next_guess(&guesses) else {
+                break;
+            };
+
+            let feedback = utils::check(answer.to_string(), guess)?;
+            let is_correct = utils::is_correct_guess(feedback.clone());
+            guesses.push(feedback);
+
+            if is_correct {
+                break;
+            }
+        }
+
+        Ok(guesses)
+    }
+}
+
+/// Wordles that are consistent with every feedback in the history
+pub fn candidates<W: AsRef<str>>(
+    guesses: &[Vec<LetterStatus>],
+    valid_wordles: &[W],
+) -> Vec<String> {
+    candidate_indices(guesses, valid_wordles)
+        .into_iter()
+        .map(|index| valid_wordles[index].as_ref().to_string())
+        .collect()
+}
+
+// positions of the wordles consistent with every feedback in the history
+fn candidate_indices<W: AsRef<str>>(
+    guesses: &[Vec<LetterStatus>],
+    valid_wordles: &[W],
+) -> Vec<usize> {
+    let feedbacks: Vec<(Vec<u8>, pattern::Pattern)> = guesses
+        .iter()
+        .map(|guess| {
+            let word: String = guess.iter().map(|x| x.letter).collect();
+            (word.into_bytes(), pattern::encode(guess))
+        })
+        .collect();
+
+    valid_wordles
+        .iter()
+        .enumerate()
+        .filter(|(_, wordle)| {
+            feedbacks.iter().all(|(guess, expected)| {
+                pattern::score(wordle.as_ref().as_bytes(), guess) == Some(*expected)
+            })
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Ranks guesses by the expected information (entropy over the feedback patterns) they give
+/// about the candidates. Ties are broken in favour of the candidates.
+pub fn rank_guesses(candidates: &[String], guesses: &[String]) -> Vec<Suggestion> {
+    // with one or two candidates left, guessing a candidate is always the best bet
+    let guesses = if candidates.len() <= 2 {
+        candidates
+    } else {
+        guesses
+    };
+
+    let mut suggestions: Vec<Suggestion> = guesses
+        .iter()
+        .map(|guess| Suggestion {
+            word: guess.to_string(),
+            entropy: entropy(guess, candidates),
+            is_candidate: candidates.contains(guess),
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        b.entropy
+            .total_cmp(&a.entropy)
+            .then(b.is_candidate.cmp(&a.is_candidate))
+            .then(a.word.cmp(&b.word))
+    });
+
+    suggestions
+}
+
+/// Expected information (in bits) of the guess over the candidates
+pub fn entropy(guess: &str, candidates: &[String]) -> f64 {
+    // one bucket per feedback pattern; 3 states for every letter
+    let mut buckets = vec![0usize; pattern::pattern_count(guess.len())];
+
+    for candidate in candidates {
+        if let Some(pattern) = pattern::score(candidate.as_bytes(), guess.as_bytes()) {
+            buckets[usize::from(pattern)] += 1;
+        }
+    }
+
+    pattern::bucket_entropy(&buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::data::{valid_guesses, valid_wordles};
+    use crate::wordle::solver::*;
+    use crate::wordle::utils::check;
+
+    #[test]
+    fn test_candidates() {
+        let wordles: Vec<String> = vec!["pious".into(), "pilot".into(), "crane".into()];
+        // pilot fits the feedback as well
+        let guesses = vec![check("pious".into(), "piano".into()).unwrap()];
+        assert_eq!(candidates(&guesses, &wordles), vec!["pious", "pilot"]);
+
+        let guesses = vec![check("pious".into(), "pilot".into()).unwrap()];
+        assert_eq!(candidates(&guesses, &wordles), vec!["pious"]);
+        assert_eq!(candidates(&[], &wordles), wordles);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let candidates: Vec<String> = vec!["pious".into(), "pilot".into()];
+
+        // both candidates give different feedback; 1 bit of information
+        assert_eq!(entropy("pious", &candidates), 1.0);
+        // same feedback for both candidates
+        assert_eq!(entropy("xxxxx", &candidates), 0.0);
+    }
+
+    #[test]
+    fn test_hints() {
+        let wordles = valid_wordles();
+        let guesses = vec![
+            check("ulcer".into(), "crane".into()).unwrap(),
+            check("ulcer".into(), "moist".into()).unwrap(),
+        ];
+
+        let hints = hints(&guesses, &wordles, &valid_guesses(), 5);
+        assert_eq!(hints.attempt, 2);
+        assert!(hints.candidates.contains(&"ulcer".to_string()));
+        assert!(!hints.suggestions.is_empty());
+        assert!(hints.suggestions.len() <= 5);
+
+        // suggestions are sorted by entropy
+        let entropies: Vec<f64> = hints.suggestions.iter().map(|x| x.entropy).collect();
+        assert!(entropies.windows(2).all(|x| x[0] >= x[1]));
+    }
+
+    #[test]
+    fn test_solver() {
+        // smaller word lists to keep the test fast
+        let wordles: Vec<String> = valid_wordles().into_iter().step_by(10).collect();
+        let guesses: Vec<String> = valid_guesses().into_iter().step_by(10).collect();
+        let answer = wordles[42].to_string();
+        let mut solver = Solver::new(Strategy::Entropy, wordles.clone(), guesses.clone());
+
+        let feedback = solver.solve(&answer, 10).unwrap();
+        assert!(utils::is_correct_guess(feedback.last().unwrap().clone()));
+
+        // same feedback history gives the same guess
+        let first_guess = solver.next_guess(&[]).unwrap();
+        assert_eq!(solver.next_guess(&[]), Some(first_guess));
+
+        let mut solver = Solver::new(Strategy::FirstCandidate, wordles.clone(), guesses);
+        assert_eq!(solver.next_guess(&[]), Some(wordles[0].to_string()));
+
+        let feedback = solver.solve(&wordles[0], 10).unwrap();
+        assert_eq!(feedback.len(), 1);
+
+        // gives up after max guesses
+        let feedback = solver.solve(&answer, 2).unwrap();
+        assert_eq!(feedback.len(), 2);
+    }
+
+    #[test]
+    fn test_solver_pattern_matrix() {
+        let wordles: Vec<String> = valid_wordles().into_iter().step_by(10).collect();
+        let guesses: Vec<String> = valid_guesses().into_iter().step_by(10).collect();
+        let mut solver = Solver::new(Strategy::Entropy, wordles.clone(), guesses.clone());
+        let mut matrix_solver =
+            Solver::new(Strategy::Entropy, wordles.clone(), guesses).with_pattern_matrix();
+
+        // matrix gives the same guesses
+        for answer in wordles.iter().step_by(7) {
+            assert_eq!(
+                matrix_solver.solve(answer, 10).unwrap(),
+                solver.solve(answer, 10).unwrap(),
+                "{}",
+                answer
+            );
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::game::MAX_ATTEMPTS;
+
+// attempts added to the budget for every solved word; solving in 4 guesses keeps the budget as is
+pub const SURVIVAL_REFILL: usize = 4;
+
+// survival: words are played one after the other from a shared attempt budget
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Survival {
+    // budget the run is started with
+    pub start_budget: usize,
+    // words solved so far in the run
+    pub words: Vec<String>,
+}
+
+impl Survival {
+    pub fn new(start_budget: usize) -> Self {
+        Survival {
+            start_budget,
+            words: Vec::new(),
+        }
+    }
+
+    // number of words solved in the run
+    pub fn run_length(&self) -> usize {
+        self.words.len()
+    }
+}
+
+// budget for the next word; unused attempts roll over, up to the most attempts the grid can show
+pub fn next_budget(budget: usize, used: usize) -> usize {
+    (budget.saturating_sub(used) + SURVIVAL_REFILL).min(MAX_ATTEMPTS)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::survival::*;
+
+    #[test]
+    fn test_next_budget() {
+        assert_eq!(next_budget(6, 4), 6);
+        assert_eq!(next_budget(6, 2), 8);
+        assert_eq!(next_budget(6, 6), 4);
+        assert_eq!(next_budget(3, 1), 6);
+
+        // budget is capped
+        assert_eq!(next_budget(13, 1), MAX_ATTEMPTS);
+    }
+}
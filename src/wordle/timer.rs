@@ -0,0 +1,155 @@
+use std::time::{Duration, Instant};
+
+// game clock; started with the first typed letter and stopped once the game is over
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Clock {
+    started_at: Option<Instant>,
+    // elapsed time is frozen once the clock is stopped
+    stopped: Option<Duration>,
+}
+
+impl Clock {
+    // starts the clock unless it is already running (or stopped)
+    pub fn start(&mut self, now: Instant) {
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+    }
+
+    pub fn stop(&mut self, now: Instant) {
+        if self.stopped.is_none() {
+            self.stopped = Some(self.elapsed(now));
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some() && self.stopped.is_none()
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        match (self.stopped, self.started_at) {
+            (Some(elapsed), _) => elapsed,
+            (None, Some(started_at)) => now.saturating_duration_since(started_at),
+            (None, None) => Duration::ZERO,
+        }
+    }
+}
+
+// finished wordle of a speedrun
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Split {
+    pub wordle: String,
+    pub won: bool,
+    pub attempts: usize,
+    pub time: Duration,
+}
+
+// speedrun: consecutive wordles played against the clock
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Speedrun {
+    // number of wordles in the run
+    pub wordles: usize,
+    pub splits: Vec<Split>,
+}
+
+impl Speedrun {
+    pub fn new(wordles: usize) -> Self {
+        Speedrun {
+            wordles,
+            splits: Vec::new(),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.splits.len() >= self.wordles
+    }
+
+    // time taken by the finished wordles; time between the wordles is not counted
+    pub fn total_time(&self) -> Duration {
+        self.splits.iter().map(|split| split.time).sum()
+    }
+
+    pub fn solved(&self) -> usize {
+        self.splits.iter().filter(|split| split.won).count()
+    }
+}
+
+// formats duration as mm:ss
+pub fn format_clock(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+// formats duration as mm:ss.t; used for speedrun splits
+pub fn format_split(duration: Duration) -> String {
+    format!(
+        "{}.{}",
+        format_clock(duration),
+        duration.subsec_millis() / 100
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordle::timer::*;
+
+    #[test]
+    fn test_clock() {
+        let now = Instant::now();
+        let mut clock = Clock::default();
+        assert_eq!(clock.elapsed(now), Duration::ZERO);
+        assert!(!clock.is_running());
+
+        clock.start(now);
+        assert!(clock.is_running());
+        assert_eq!(
+            clock.elapsed(now + Duration::from_secs(3)),
+            Duration::from_secs(3)
+        );
+
+        // starting again does not reset the clock
+        clock.start(now + Duration::from_secs(2));
+        assert_eq!(
+            clock.elapsed(now + Duration::from_secs(3)),
+            Duration::from_secs(3)
+        );
+
+        // stopped clock keeps the elapsed time
+        clock.stop(now + Duration::from_secs(5));
+        assert!(!clock.is_running());
+        assert_eq!(
+            clock.elapsed(now + Duration::from_secs(60)),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn test_speedrun() {
+        let mut speedrun = Speedrun::new(2);
+        speedrun.splits.push(Split {
+            wordle: "pious".into(),
+            won: true,
+            attempts: 3,
+            time: Duration::from_millis(41_200),
+        });
+        assert!(!speedrun.is_finished());
+
+        speedrun.splits.push(Split {
+            wordle: "crane".into(),
+            won: false,
+            attempts: 6,
+            time: Duration::from_millis(80_000),
+        });
+        assert!(speedrun.is_finished());
+        assert_eq!(speedrun.solved(), 1);
+        assert_eq!(speedrun.total_time(), Duration::from_millis(121_200));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format_clock(Duration::from_secs(0)), "00:00");
+        assert_eq!(format_clock(Duration::from_millis(83_900)), "01:23");
+        assert_eq!(format_split(Duration::from_millis(83_950)), "01:23.9");
+    }
+}
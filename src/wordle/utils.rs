@@ -1,5 +1,13 @@
+use super::dictionary::Dictionary;
+use super::error::{HardModeViolation, WordlError};
 use super::model::{KeyboardHints, LetterState, LetterStatus};
 
+// default number of letters in a wordle
+pub const WORD_LENGTH: usize = 5;
+// supported number of letters in a wordle
+pub const MIN_WORD_LENGTH: usize = 4;
+pub const MAX_WORD_LENGTH: usize = 8;
+
 // wordle is compared with incoming string and each character is parsed for its correctness.
 // There are three cases - correct position, incorrect position, unknown (not present etc)
 // CASE 1: Correct position - letter matches exact the position of the wordle
@@ -8,17 +16,19 @@ use super::model::{KeyboardHints, LetterState, LetterStatus};
 // check has to performed for total number of those letters in the wordle and the guessed word. The
 // letter is marked as incorrect if wordle has more or equal number of letters in the guessed word
 // CASE 3: unknown - letter is not present in the string at all
-pub fn check(wordle: String, guess: String) -> Vec<LetterStatus> {
-    let mut output: Vec<LetterStatus> = Vec::with_capacity(5);
+pub fn check(wordle: String, guess: String) -> Result<Vec<LetterStatus>, WordlError> {
     // first things first; let use make sure we have the right length words
-    if wordle.len() != 5 && guess.len() != 5 {
-        panic!("5 letter wordle and guess word is needed. Aborting");
-    }
+    let length = wordle.chars().count();
+    validate_word_length(length)?;
+    validate_word(&wordle, length)?;
+    validate_word(&guess, length)?;
+
+    let mut output: Vec<LetterStatus> = Vec::with_capacity(length);
 
     let wordle_letters: Vec<char> = wordle.chars().collect();
     let guess_letters: Vec<char> = guess.chars().collect();
 
-    for position in 0..5 {
+    for position in 0..length {
         let wordle_letter = wordle_letters[position];
         let guess_letter = guess_letters[position];
         // CASE 1: correct position
@@ -79,22 +89,119 @@ pub fn check(wordle: String, guess: String) -> Vec<LetterStatus> {
         }
     }
 
-    output
+    Ok(output)
+}
+
+// wordle can have 4 to 8 letters
+pub fn validate_word_length(length: usize) -> Result<(), WordlError> {
+    if (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&length) {
+        Ok(())
+    } else {
+        Err(WordlError::UnsupportedLength(length))
+    }
+}
+
+// word must have the right number of letters, and only ascii letters
+pub fn validate_word(word: &str, length: usize) -> Result<(), WordlError> {
+    let actual = word.chars().count();
+    if actual != length {
+        return Err(WordlError::WrongLength {
+            expected: length,
+            actual,
+        });
+    }
+
+    match word.chars().find(|x| !x.is_ascii_alphabetic()) {
+        Some(character) => Err(WordlError::NonAlphabetic(character)),
+        None => Ok(()),
+    }
+}
+
+// guess has to be a valid word present in the word lists
+pub fn validate_guess(
+    guess: &str,
+    length: usize,
+    dictionary: &Dictionary,
+) -> Result<(), WordlError> {
+    validate_word(guess, length)?;
+
+    if dictionary.contains(guess) {
+        Ok(())
+    } else {
+        Err(WordlError::NotInWordList(guess.to_string()))
+    }
+}
+
+// hard mode: every revealed hint must be used in the subsequent guesses
+// correct letters (green) have to stay in the same position and incorrectly placed letters
+// (yellow) have to be present in the guess (atleast as many times as they were revealed)
+pub fn check_hard_mode(guesses: &[Vec<LetterStatus>], guess: &str) -> Result<(), WordlError> {
+    let guess_letters: Vec<char> = guess.chars().collect();
+
+    for previous in guesses {
+        for (position, letter_status) in previous.iter().enumerate() {
+            if letter_status.status == LetterState::Correct
+                && guess_letters.get(position) != Some(&letter_status.letter)
+            {
+                return Err(WordlError::HardModeViolation(
+                    HardModeViolation::CorrectLetter {
+                        position,
+                        letter: letter_status.letter,
+                    },
+                ));
+            }
+        }
+    }
+
+    for previous in guesses {
+        for letter_status in previous {
+            if letter_status.status != LetterState::Incorrect {
+                continue;
+            }
+
+            let letter = letter_status.letter;
+            let revealed = previous
+                .iter()
+                .filter(|x| {
+                    x.letter == letter
+                        && (x.status == LetterState::Correct || x.status == LetterState::Incorrect)
+                })
+                .count();
+            let used = guess_letters.iter().filter(|&&x| x == letter).count();
+
+            if used < revealed {
+                return Err(WordlError::HardModeViolation(
+                    HardModeViolation::PresentLetter { letter },
+                ));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 pub fn is_correct_guess(input: Vec<LetterStatus>) -> bool {
     input.iter().all(|x| x.status == LetterState::Correct)
 }
 
+// 64 bit fnv-1a hash; stable across versions and platforms, unlike the std hasher
+pub fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes
+        .into_iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
 // helper function to update keyboard hints
-pub fn update_keyboard_hints<'a>(
-    hints: &'a mut KeyboardHints,
+pub fn update_keyboard_hints(
+    hints: &mut KeyboardHints,
     statuses: Vec<LetterStatus>,
-) -> &'a mut KeyboardHints {
+) -> &mut KeyboardHints {
     for status in statuses {
         // if the key is not present; just update with the value
         if !hints.contains_key(&status.letter) {
-            hints.insert(status.letter.clone(), status.status.clone());
+            hints.insert(status.letter, status.status.clone());
         } else {
             // key is present; based on the status we have to match stuff
             match status.status {
@@ -102,7 +209,7 @@ pub fn update_keyboard_hints<'a>(
                     // we will update only if it is not already correct
                     if let Some(current_status) = hints.get(&status.letter) {
                         if *current_status != LetterState::Correct {
-                            hints.insert(status.letter.clone(), status.status.clone());
+                            hints.insert(status.letter, status.status.clone());
                         }
                     }
                 }
@@ -112,13 +219,13 @@ pub fn update_keyboard_hints<'a>(
                         if *current_status != LetterState::Correct
                             && *current_status != LetterState::Incorrect
                         {
-                            hints.insert(status.letter.clone(), status.status.clone());
+                            hints.insert(status.letter, status.status.clone());
                         }
                     }
                 }
                 // correct is always correct!
                 LetterState::Correct => {
-                    hints.insert(status.letter.clone(), LetterState::Correct);
+                    hints.insert(status.letter, LetterState::Correct);
                 }
             }
         }
@@ -130,14 +237,11 @@ pub fn update_keyboard_hints<'a>(
 fn get_all_letter_indices(letter: char, word: String) -> Vec<usize> {
     let mut output: Vec<usize> = Vec::new();
 
-    word.chars()
-        .enumerate()
-        .into_iter()
-        .for_each(|(index, word_letter)| {
-            if word_letter == letter {
-                output.push(index);
-            }
-        });
+    word.chars().enumerate().for_each(|(index, word_letter)| {
+        if word_letter == letter {
+            output.push(index);
+        }
+    });
 
     output
 }
@@ -147,7 +251,7 @@ fn intersection_match(wordle: Vec<usize>, guess: Vec<usize>) -> Vec<usize> {
 
     wordle.into_iter().for_each(|i| {
         if guess.contains(&i) {
-            output.push(i.clone());
+            output.push(i);
         }
     });
 
@@ -156,6 +260,7 @@ fn intersection_match(wordle: Vec<usize>, guess: Vec<usize>) -> Vec<usize> {
 
 #[cfg(test)]
 mod tests {
+    use crate::wordle::error::{HardModeViolation, WordlError};
     use crate::wordle::model::{LetterState, LetterStatus};
     use crate::wordle::utils::*;
     use std::collections::HashMap;
@@ -166,7 +271,7 @@ mod tests {
         assert_eq!(output, vec![2]);
 
         let output = intersection_match(vec![0, 2], vec![3]);
-        assert_eq!(output, vec![]);
+        assert_eq!(output, Vec::<usize>::new());
 
         let output = intersection_match(vec![1], vec![1, 3]);
         assert_eq!(output, vec![1]);
@@ -189,7 +294,7 @@ mod tests {
 
     #[test]
     fn test_status_random() {
-        let output = check("ennui".into(), "where".into());
+        let output = check("ennui".into(), "where".into()).unwrap();
 
         let expected: Vec<LetterStatus> = vec![
             LetterStatus {
@@ -217,7 +322,7 @@ mod tests {
         assert_eq!(output, expected);
 
         // test another
-        let output = check("drove".into(), "evoke".into());
+        let output = check("drove".into(), "evoke".into()).unwrap();
 
         let expected: Vec<LetterStatus> = vec![
             LetterStatus {
@@ -245,7 +350,7 @@ mod tests {
         assert_eq!(output, expected);
 
         // test another
-        let output = check("milky".into(), "livid".into());
+        let output = check("milky".into(), "livid".into()).unwrap();
 
         let expected: Vec<LetterStatus> = vec![
             LetterStatus {
@@ -323,7 +428,7 @@ mod tests {
             },
         ];
 
-        assert_eq!(is_correct_guess(not_correct_input), false);
+        assert!(!is_correct_guess(not_correct_input));
     }
 
     #[test]
@@ -331,7 +436,7 @@ mod tests {
         // WORDLE - PIOUS
         let wordle = String::from("pious");
         let mut hints: KeyboardHints = HashMap::new();
-        let statuses: Vec<LetterStatus> = check(wordle.clone(), "piano".into());
+        let statuses: Vec<LetterStatus> = check(wordle.clone(), "piano".into()).unwrap();
 
         update_keyboard_hints(&mut hints, statuses);
 
@@ -347,7 +452,7 @@ mod tests {
 
         // check new guess word where already correct letter is now in incorrect position; hint
         // should show 'correct' because it was correct once
-        let statuses: Vec<LetterStatus> = check(wordle.clone(), "smile".into());
+        let statuses: Vec<LetterStatus> = check(wordle.clone(), "smile".into()).unwrap();
 
         // update hints again
         update_keyboard_hints(&mut hints, statuses);
@@ -362,7 +467,7 @@ mod tests {
 
         // check new wordle
         // WORDLE - below; Word - Hello
-        let statuses: Vec<LetterStatus> = check(wordle.into(), "hello".into());
+        let statuses: Vec<LetterStatus> = check(wordle.into(), "hello".into()).unwrap();
 
         // update hints again
         update_keyboard_hints(&mut hints, statuses);
@@ -370,4 +475,133 @@ mod tests {
         // i => already correct, it should still be correct
         assert_eq!(*hints.get(&'l').unwrap(), LetterState::Correct);
     }
+
+    #[test]
+    fn test_hard_mode() {
+        // WORDLE - PIOUS
+        let guesses = vec![check("pious".into(), "piano".into()).unwrap()];
+
+        // p, i are correct; o is in incorrect position
+        assert_eq!(check_hard_mode(&guesses, "pilot"), Ok(()));
+        assert_eq!(
+            check_hard_mode(&guesses, "radio"),
+            Err(WordlError::HardModeViolation(
+                HardModeViolation::CorrectLetter {
+                    position: 0,
+                    letter: 'p'
+                }
+            ))
+        );
+        assert_eq!(
+            check_hard_mode(&guesses, "prawn"),
+            Err(WordlError::HardModeViolation(
+                HardModeViolation::CorrectLetter {
+                    position: 1,
+                    letter: 'i'
+                }
+            ))
+        );
+        assert_eq!(
+            check_hard_mode(&guesses, "pints"),
+            Err(WordlError::HardModeViolation(
+                HardModeViolation::PresentLetter { letter: 'o' }
+            ))
+        );
+
+        // no hints yet; any guess is fine
+        assert_eq!(check_hard_mode(&[], "xylyl"), Ok(()));
+
+        // repeated letters have to be reused as many times as revealed
+        // WORDLE - EERIE
+        let guesses = vec![check("eerie".into(), "beget".into()).unwrap()];
+        assert_eq!(
+            check_hard_mode(&guesses, "mesas"),
+            Err(WordlError::HardModeViolation(
+                HardModeViolation::PresentLetter { letter: 'e' }
+            ))
+        );
+        assert_eq!(check_hard_mode(&guesses, "melee"), Ok(()));
+    }
+
+    #[test]
+    fn test_check_errors() {
+        // mismatched lengths are reported instead of panicking
+        assert_eq!(
+            check("pious".into(), "pio".into()),
+            Err(WordlError::WrongLength {
+                expected: 5,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            check("pi".into(), "pious".into()),
+            Err(WordlError::UnsupportedLength(2))
+        );
+        assert_eq!(
+            check("pious".into(), "pi0us".into()),
+            Err(WordlError::NonAlphabetic('0'))
+        );
+    }
+
+    #[test]
+    fn test_validate_guess() {
+        let valid_wordles: Vec<String> = vec!["pious".into()];
+        let valid_guesses: Vec<String> = vec!["aahed".into()];
+        let dictionary = Dictionary::from_lists(&valid_wordles, &valid_guesses);
+
+        assert_eq!(validate_guess("pious", 5, &dictionary), Ok(()));
+        assert_eq!(validate_guess("aahed", 5, &dictionary), Ok(()));
+        assert_eq!(
+            validate_guess("xxxxx", 5, &dictionary),
+            Err(WordlError::NotInWordList("xxxxx".into()))
+        );
+        assert_eq!(
+            validate_guess("piou", 5, &dictionary),
+            Err(WordlError::WrongLength {
+                expected: 5,
+                actual: 4
+            })
+        );
+
+        // bundled lists
+        let dictionary = Dictionary::bundled();
+        assert_eq!(validate_guess("aahed", 5, &dictionary), Ok(()));
+        assert_eq!(
+            validate_guess("xxxxx", 5, &dictionary),
+            Err(WordlError::NotInWordList("xxxxx".into()))
+        );
+    }
+
+    #[test]
+    fn test_check_word_length() {
+        // 4 letter wordle
+        let output = check("wolf".into(), "flow".into()).unwrap();
+        let statuses: Vec<LetterState> = output.into_iter().map(|x| x.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                LetterState::Incorrect,
+                LetterState::Incorrect,
+                LetterState::Incorrect,
+                LetterState::Incorrect
+            ]
+        );
+
+        // 8 letter wordle
+        let output = check("mountain".into(), "mounting".into()).unwrap();
+        assert_eq!(output.len(), 8);
+        assert!(!is_correct_guess(output));
+
+        assert_eq!(
+            check("wolf".into(), "pious".into()),
+            Err(WordlError::WrongLength {
+                expected: 4,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            check("wolfpacks".into(), "wolfpacks".into()),
+            Err(WordlError::UnsupportedLength(9))
+        );
+    }
 }